@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum AccidentError {
+    #[msg("Only inspectors, insurers and law enforcement can record accidents")]
+    NotAuthorizedReporter,
+
+    #[msg("Reporter must be verified")]
+    ReporterNotVerified,
+
+    #[msg("Accident date cannot be in the future")]
+    InvalidAccidentDate,
+
+    #[msg("At least one damaged zone must be given")]
+    NoDamagedZones,
+
+    #[msg("Invalid document URI - must be non-empty and 200 characters or less")]
+    InvalidDocumentUri,
+
+    #[msg("Accident count overflow")]
+    AccidentCountOverflow,
+
+    #[msg("Repair status can only move forward")]
+    InvalidRepairStatus,
+}
@@ -26,6 +26,32 @@ pub enum CarError {
     #[msg("غير مخول  : فقط المالك يمكن الوصول إلى هذه البيانات ")]
     UnauthorizedAccess,
     #[msg("السعر غير محدد")]
-    SalePriceNotSet
+    SalePriceNotSet,
+    #[msg("السيارة معروضة للبيع مسبقاً")]
+    CarAlreadyForSale,
+    #[msg("السعر الجديد مطابق للسعر الحالي")]
+    PriceUnchanged,
+    #[msg("تجاوز رقم إصدار العرض الحد المسموح")]
+    ListingVersionOverflow,
+    #[msg("طلب الشراء لا يزال صالحاً للعرض الحالي")]
+    BuyRequestStillActive,
+    #[msg("تاريخ انتهاء التفويض يجب أن يكون في المستقبل")]
+    InvalidDelegateExpiry,
+    #[msg("نطاق التفويض فارغ")]
+    EmptyDelegateScope,
+    #[msg("لا يوجد تفويض على هذه السيارة")]
+    NoDelegate,
+    #[msg("تجاوز عدد عمليات نقل الملكية الحد المسموح")]
+    TransferCountOverflow,
+    #[msg("السيارة مبلغ عن سرقتها")]
+    CarReportedStolen,
+    #[msg("السيارة غير مبلغ عن سرقتها")]
+    CarNotStolen,
+    #[msg("غير مخول: فقط المالك أو الشرطة يمكنهم الإبلاغ عن السرقة")]
+    UnauthorizedTheftReport,
+    #[msg("السيارة ملغاة التسجيل")]
+    CarDeregistered,
+    #[msg("يجب الاطلاع على سجل الحوادث الحالي للسيارة قبل طلب الشراء")]
+    AccidentHistoryNotAcknowledged,
 
 }
\ No newline at end of file
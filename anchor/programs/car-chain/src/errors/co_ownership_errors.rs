@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum CoOwnershipError {
+    #[msg("Co-ownership needs between 2 and 5 distinct owners")]
+    InvalidOwnerCount,
+
+    #[msg("Co-owner shares must add up to 10000 basis points")]
+    InvalidShares,
+
+    #[msg("Approval threshold must be a majority (more than 5000 basis points)")]
+    InvalidThreshold,
+
+    #[msg("The current owner must be one of the co-owners")]
+    OwnerNotCoOwner,
+
+    #[msg("Car is co-owned")]
+    CarCoOwned,
+
+    #[msg("Car is not co-owned")]
+    CarNotCoOwned,
+
+    #[msg("Co-ownership account is required for this car")]
+    CoOwnershipAccountMissing,
+
+    #[msg("Signing co-owners do not hold the required share")]
+    InsufficientApproval,
+
+    #[msg("A writable account for every co-owner is required to split proceeds")]
+    CoOwnerAccountMissing,
+
+    #[msg("Every listed co-owner must sign to accept their share")]
+    CoOwnerSignatureMissing,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum DealerError {
+    #[msg("Only users with the Dealer role can register a dealership")]
+    NotDealer,
+
+    #[msg("Dealer must be verified")]
+    DealerNotVerified,
+
+    #[msg("Invalid licence number: must be between 1-50 characters")]
+    InvalidLicence,
+
+    #[msg("Dealer staff list is full")]
+    TooManyStaff,
+
+    #[msg("Staff member is already registered")]
+    StaffAlreadyAdded,
+
+    #[msg("Staff member not found")]
+    StaffNotFound,
+
+    #[msg("Signer is not the dealer or one of its staff")]
+    UnauthorizedDealerStaff,
+
+    #[msg("Invalid commission - must be at most 10000 basis points")]
+    InvalidCommission,
+
+    #[msg("Car is consigned to a dealer")]
+    CarConsigned,
+
+    #[msg("Car is not consigned to this dealer")]
+    CarNotConsigned,
+
+    #[msg("Too many cars in one batch")]
+    BatchTooLarge,
+
+    #[msg("Number of cars does not match number of prices")]
+    BatchLengthMismatch,
+
+    #[msg("Car account must be writable")]
+    CarNotWritable,
+}
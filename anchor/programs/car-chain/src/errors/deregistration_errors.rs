@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum DeregistrationError {
+    #[msg("Invalid certificate URI - must be non-empty and 200 characters or less")]
+    InvalidCertificate,
+
+    #[msg("Car is not deregistered")]
+    CarNotDeregistered,
+
+    #[msg("Only exported vehicles can be re-registered")]
+    ReregistrationNotAllowed,
+
+    #[msg("Deregistration record does not match the car's last deregistration")]
+    DeregistrationRecordMismatch,
+
+    #[msg("Registration count overflow")]
+    RegistrationCountOverflow,
+}
@@ -0,0 +1,7 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum DocumentError {
+    #[msg("Invalid document digest - the hash must be set and the media type must be type/subtype")]
+    InvalidDocumentDigest,
+}
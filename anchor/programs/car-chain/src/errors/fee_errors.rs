@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum FeeError {
+    #[msg("Invalid fee rate - must be at most 10000 basis points")]
+    InvalidFeeBps,
+
+    #[msg("Treasury balance is too low for this withdrawal")]
+    InsufficientTreasuryFunds,
+
+    #[msg("Fee amount overflow")]
+    FeeOverflow,
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum InspectionCenterError {
+    #[msg("Invalid licence number: must be between 1-50 characters")]
+    InvalidLicence,
+
+    #[msg("Invalid centre name: must be between 1-100 characters")]
+    InvalidName,
+
+    #[msg("Invalid address URI")]
+    InvalidAddressUri,
+
+    #[msg("Capacity must be greater than zero")]
+    InvalidCapacity,
+
+    #[msg("Approved inspection types must be non-empty and unique")]
+    InvalidInspectionTypes,
+
+    #[msg("Only verified inspectors can join an inspection centre")]
+    NotInspector,
+
+    #[msg("Inspection centre inspector list is full")]
+    TooManyInspectors,
+
+    #[msg("Inspector already belongs to this centre")]
+    InspectorAlreadyMember,
+
+    #[msg("Inspector does not belong to this centre")]
+    InspectorNotMember,
+
+    #[msg("Inspection centre licence is suspended")]
+    CenterLicenceSuspended,
+
+    #[msg("Inspection centre is not approved for this inspection type")]
+    InspectionTypeNotApproved,
+}
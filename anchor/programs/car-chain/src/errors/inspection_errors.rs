@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum InspectionError {
+    #[msg("Too many inspection validity rules - at most 16")]
+    TooManyRules,
+
+    #[msg("Invalid validity period - must be positive")]
+    InvalidValidityPeriod,
+
+    #[msg("Validity rules must be sorted by age in ascending order within each category")]
+    RulesNotSorted,
+
+    #[msg("A valid, unexpired inspection is required to list this car")]
+    ValidInspectionRequired,
+
+    #[msg("Car account must be writable")]
+    CarNotWritable,
+
+    #[msg("Invalid inspection window - must end after it starts and in the future")]
+    InvalidInspectionWindow,
+
+    #[msg("Inspection request is not open")]
+    InspectionRequestNotOpen,
+
+    #[msg("The requested inspection window has passed")]
+    InspectionWindowClosed,
+
+    #[msg("Inspection request has not been claimed by this inspector")]
+    NotAssignedInspector,
+
+    #[msg("Report inspection type does not match the request")]
+    InspectionTypeMismatch,
+
+    #[msg("Inspection request can only be cancelled while open or after a missed window")]
+    CannotCancelInspection,
+
+    #[msg("Inspector is suspended")]
+    InspectorSuspended,
+
+    #[msg("Inspector is not suspended")]
+    InspectorNotSuspended,
+
+    #[msg("Inspector stats do not belong to the report's inspector")]
+    InspectorStatsMismatch,
+
+    #[msg("Inspection request was booked by a previous owner of the car")]
+    StaleInspectionRequest,
+}
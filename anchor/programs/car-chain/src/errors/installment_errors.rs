@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum InstallmentError {
+    #[msg("Invalid plan: down payment plus instalments must equal the total price")]
+    InvalidPlanAmounts,
+
+    #[msg("Invalid plan schedule: instalment count and interval must be positive")]
+    InvalidSchedule,
+
+    #[msg("Invalid default refund - must be at most 10000 basis points")]
+    InvalidRefundRate,
+
+    #[msg("Car is under an instalment plan")]
+    CarUnderInstallmentPlan,
+
+    #[msg("Instalment plan is not in the expected state")]
+    InvalidPlanStatus,
+
+    #[msg("All instalments have already been paid")]
+    PlanFullyPaid,
+
+    #[msg("Instalments are still outstanding")]
+    PlanNotFullyPaid,
+
+    #[msg("Buyer is not in default")]
+    NotInDefault,
+
+    #[msg("Only the seller, or the buyer once the seller no longer owns the car, can cancel")]
+    CannotCancelPlan,
+
+    #[msg("Amount overflow")]
+    AmountOverflow,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum LienError {
+    #[msg("Only users with the Lender role can register liens")]
+    NotLender,
+
+    #[msg("Lender must be verified")]
+    LenderNotVerified,
+
+    #[msg("Car already has an active lien")]
+    LienAlreadyActive,
+
+    #[msg("Car has an active lien")]
+    CarHasLien,
+
+    #[msg("Lien and lender accounts are required for this car")]
+    LienAccountMissing,
+
+    #[msg("Sale proceeds do not cover the lien payoff")]
+    PayoffExceedsProceeds,
+
+    #[msg("Invalid lien: outstanding amount must be positive and at most the principal")]
+    InvalidLienAmount,
+
+    #[msg("Loan reference is too long - must be 64 characters or less")]
+    LoanReferenceTooLong,
+
+    #[msg("Lien payoff can only be reduced")]
+    PayoffIncrease,
+}
@@ -2,8 +2,34 @@ pub mod error_codes;
 pub mod custom_errors;
 pub mod car_errors;
 pub mod report_errors;
+pub mod fee_errors;
+pub mod tax_errors;
+pub mod dealer_errors;
+pub mod transfer_errors;
+pub mod co_ownership_errors;
+pub mod organization_errors;
+pub mod lien_errors;
+pub mod installment_errors;
+pub mod deregistration_errors;
+pub mod accident_errors;
+pub mod inspection_errors;
+pub mod document_errors;
+pub mod inspection_center_errors;
 
 pub use error_codes::*;
 pub use custom_errors::*;
 pub use car_errors::*;
-pub use report_errors::*;
\ No newline at end of file
+pub use report_errors::*;
+pub use fee_errors::*;
+pub use tax_errors::*;
+pub use dealer_errors::*;
+pub use transfer_errors::*;
+pub use co_ownership_errors::*;
+pub use organization_errors::*;
+pub use lien_errors::*;
+pub use installment_errors::*;
+pub use deregistration_errors::*;
+pub use accident_errors::*;
+pub use inspection_errors::*;
+pub use document_errors::*;
+pub use inspection_center_errors::*;
\ No newline at end of file
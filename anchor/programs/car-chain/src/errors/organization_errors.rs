@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum OrganizationError {
+    #[msg("Invalid organization name: must be between 1-50 characters")]
+    InvalidName,
+
+    #[msg("Invalid registration number: must be between 1-50 characters")]
+    InvalidRegistrationNumber,
+
+    #[msg("Only organization admins can perform this action")]
+    NotOrganizationAdmin,
+
+    #[msg("Organization member list is full")]
+    TooManyMembers,
+
+    #[msg("Member not found")]
+    MemberNotFound,
+
+    #[msg("An organization must keep at least one admin")]
+    LastAdmin,
+
+    #[msg("Organization has already been processed")]
+    OrganizationAlreadyProcessed,
+
+    #[msg("Organization balance is too low for this withdrawal")]
+    InsufficientFunds,
+
+    #[msg("Organization is not verified")]
+    OrganizationNotVerified,
+}
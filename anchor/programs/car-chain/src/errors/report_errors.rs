@@ -20,6 +20,10 @@ pub enum CarReportError {
     SummaryTooLong,
     #[msg("Invalid report - car mismatch")]
     InvalidReport,
+    #[msg("Invalid checklist - unknown items or out-of-range measurements")]
+    InvalidChecklist,
+    #[msg("A report with failed checklist items cannot pass")]
+    ChecklistContradictsVerdict,
 }
 
 #[error_code]
@@ -37,3 +41,25 @@ pub enum ConfirmityReportError {
     #[msg("Notes field too long")]
     NotesTooLong,
 }
+
+#[error_code]
+pub enum ReportStatusError {
+    #[msg("Report is not pending")]
+    ReportNotPending,
+    #[msg("Report is not disputed")]
+    ReportNotDisputed,
+    #[msg("Dispute reason is too long - must be 200 characters or less")]
+    DisputeReasonTooLong,
+    #[msg("Invalid evidence URI - must be non-empty and 200 characters or less")]
+    InvalidEvidenceUri,
+    #[msg("Exactly one car report or conformity report must be given")]
+    InvalidReportAccounts,
+    #[msg("Only pending or accepted reports can be amended")]
+    ReportNotAmendable,
+    #[msg("Report has already been superseded, revoked or voided")]
+    ReportNotLive,
+    #[msg("Only the issuing inspector or the government can revoke a report")]
+    NotReportIssuer,
+    #[msg("Report revision overflow")]
+    RevisionOverflow,
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TaxError {
+    #[msg("Too many tax brackets - at most 8 per table")]
+    TooManyBrackets,
+
+    #[msg("Invalid tax rate - must be at most 10000 basis points")]
+    InvalidTaxRate,
+
+    #[msg("Tax brackets must be sorted in ascending order")]
+    BracketsNotSorted,
+
+    #[msg("Tax amount overflow")]
+    TaxOverflow,
+
+    #[msg("Insufficient tax treasury funds")]
+    InsufficientTaxFunds,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum TransferError {
+    #[msg("Transfer expiry must be in the future")]
+    InvalidTransferExpiry,
+
+    #[msg("Pending transfer has expired")]
+    TransferExpired,
+
+    #[msg("Cannot transfer a car to its current owner")]
+    CannotTransferToSelf,
+
+    #[msg("Car owner changed since the transfer was initiated")]
+    OwnerChanged,
+
+    #[msg("Only the recipient can respond to this transfer")]
+    UnauthorizedRecipient,
+
+    #[msg("Only the initiator can cancel this transfer")]
+    UnauthorizedInitiator,
+
+    #[msg("Legal document URI must be between 1-200 characters")]
+    InvalidLegalDocument,
+
+    #[msg("Buy requests to refund must be passed as (buy_request, buyer) pairs for this car")]
+    InvalidRefundAccounts,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::FeeKind;
+
+#[event]
+pub struct FeeCollected {
+    pub kind: FeeKind,
+    pub vin: String,
+    pub payer: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TaxTreasuryWithdrawn {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VehicleReportedStolen {
+    pub car: Pubkey,
+    pub vin: String,
+    pub reported_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VehicleRecovered {
+    pub car: Pubkey,
+    pub vin: String,
+    pub recovered_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct InspectorSuspended {
+    pub inspector: Pubkey,
+    pub reports_voided: u32,
+    pub timestamp: i64,
+}
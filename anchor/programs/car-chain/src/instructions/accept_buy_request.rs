@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::state::{BuyRequest, BuyRequestStatus, CarAccount, UserAccount, VerificationStatus};
-use crate::errors::{CarError, CustomError};
+use crate::state::{BuyRequest, BuyRequestStatus, CarAccount, CoOwnership, Consignment, DealerAccount, DelegateAction, FeeConfig, FeeKind, Lien, Organization, OwnershipRecord, TaxConfig, TaxReceipt, TaxTreasury, TransferMechanism, Treasury, UserAccount, VerificationStatus};
+use crate::errors::{CarError, CoOwnershipError, CustomError, DealerError, LienError, OrganizationError};
+use crate::events::FeeCollected;
 use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::is_car_action_authorized;
 
 #[derive(Accounts)]
 #[instruction(vin: String, buyer: Pubkey)]
@@ -21,31 +23,121 @@ pub struct AcceptBuyRequest<'info> {
         bump,
         constraint = car.owner == owner.key() @ CarError::UnauthorizedAccess,
         constraint = car.is_for_sale @ CarError::CarNotForSale,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+        constraint = car.is_active @ CarError::CarDeregistered,
     )]
     pub car: Account<'info, CarAccount>,
 
+    // Required unless the car is owned by an organization
     #[account(
         seeds = [b"user", owner.key().as_ref(), owner_pda.user_name.as_bytes()],
         bump,
         constraint = owner_pda.verification_status == VerificationStatus::Verified @ CustomError::UserNotVerified
     )]
-    pub owner_pda: Account<'info, UserAccount>,
+    pub owner_pda: Option<Account<'info, UserAccount>>,
 
+    // Required unless the buyer is an organization
     #[account(
         seeds = [b"user", buyer.as_ref(), buyer_pda.user_name.as_bytes()],
         bump,
         constraint = buyer_pda.verification_status == VerificationStatus::Verified @ CustomError::UserNotVerified
     )]
-    pub buyer_pda: Account<'info, UserAccount>,
+    pub buyer_pda: Option<Account<'info, UserAccount>>,
 
-    /// CHECK: This is the current car owner
+    // Required when the buyer is an organization
+    #[account(
+        seeds = [b"organization", buyer_organization.name.as_bytes()],
+        bump = buyer_organization.bump,
+        constraint = buyer_organization.key() == buyer @ CarError::UnauthorizedAccess,
+        constraint = buyer_organization.verification_status == VerificationStatus::Verified @ OrganizationError::OrganizationNotVerified
+    )]
+    pub buyer_organization: Option<Account<'info, Organization>>,
+
+    /// CHECK: This is the current car owner (for receiving the sale proceeds)
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    // The owner, a delegate allowed to accept offers, or the dealer's staff
+    // when the car is consigned
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"consignment", car.key().as_ref()],
+        bump = consignment.bump
+    )]
+    pub consignment: Option<Account<'info, Consignment>>,
+
+    #[account(mut)]
+    pub dealer: Option<Account<'info, DealerAccount>>,
+
+    /// CHECK: The dealer's wallet (for receiving the commission)
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub dealer_wallet: Option<UncheckedAccount<'info>>,
+
+    // Required when the car is co-owned; the other co-owners are passed as
+    // remaining accounts, signing for approval and writable for their share
+    #[account(
+        mut,
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    // Required while the car has a lien; the payoff is routed to the lender
+    #[account(
+        mut,
+        seeds = [b"lien", car.key().as_ref()],
+        bump = lien.bump
+    )]
+    pub lien: Option<Account<'info, Lien>>,
+
+    /// CHECK: The lender's wallet (for receiving the lien payoff)
+    #[account(mut)]
+    pub lienholder: Option<UncheckedAccount<'info>>,
+
+    // Required when the car is owned by an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
 
     /// CHECK: This is the buyer (for receiving payment)
     #[account(mut)]
     pub buyer_account: UncheckedAccount<'info>,
 
+    #[account(seeds = [b"fee_config"], bump = fee_config.bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(seeds = [b"tax_config"], bump = tax_config.bump)]
+    pub tax_config: Account<'info, TaxConfig>,
+
+    #[account(mut, seeds = [b"tax_treasury"], bump = tax_treasury.bump)]
+    pub tax_treasury: Account<'info, TaxTreasury>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = TaxReceipt::LEN,
+        seeds = [b"tax_receipt", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub tax_receipt: Account<'info, TaxReceipt>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = OwnershipRecord::LEN,
+        seeds = [b"ownership_record", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub ownership_record: Account<'info, OwnershipRecord>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -59,23 +151,197 @@ pub fn handler(
 
     // Verify the buyer matches
     require!(buy_request.buyer == buyer, CarError::UnauthorizedAccess);
+    require!(
+        ctx.accounts.buyer_pda.is_some() || ctx.accounts.buyer_organization.is_some(),
+        CustomError::UserNotVerified
+    );
+
+    // Individual sellers must be verified users; organizations are verified as a whole
+    let is_organization_owned = ctx.accounts.organization
+        .as_ref()
+        .is_some_and(|organization| organization.key() == car.owner);
+    require!(
+        is_organization_owned || ctx.accounts.owner_pda.is_some(),
+        CustomError::UserNotVerified
+    );
+
+    // A consigned car is sold through its consignment so the dealer is paid
+    // and the consignment is wound up; otherwise only the owner or a delegate may accept.
+    let authority = ctx.accounts.authority.key();
+    let now = Clock::get()?.unix_timestamp;
+    let is_owner = is_car_action_authorized(
+        car,
+        ctx.accounts.co_ownership.as_deref(),
+        ctx.accounts.organization.as_ref(),
+        &authority,
+        ctx.remaining_accounts,
+        now,
+        DelegateAction::AcceptOffers,
+    )?;
+    let consignment = match (
+        car.consigned_to,
+        &ctx.accounts.consignment,
+        &ctx.accounts.dealer,
+        &ctx.accounts.dealer_wallet,
+    ) {
+        (Some(dealer_key), Some(consignment), Some(dealer), Some(dealer_wallet)) => {
+            require_keys_eq!(dealer.key(), dealer_key, DealerError::CarNotConsigned);
+            require_keys_eq!(consignment.dealer, dealer_key, DealerError::CarNotConsigned);
+            require_keys_eq!(dealer_wallet.key(), dealer.authority, DealerError::UnauthorizedDealerStaff);
+            require!(is_owner || dealer.is_authorized(&authority), DealerError::UnauthorizedDealerStaff);
+            Some(consignment)
+        }
+        (Some(_), _, _, _) => return err!(DealerError::CarConsigned),
+        (None, _, _, _) => {
+            require!(is_owner, CarError::UnauthorizedAccess);
+            None
+        }
+    };
 
-    // Transfer payment from buyer to owner
+    // Transfer payment from buyer to owner. Requests made against an older
+    // listing version are honoured at the amount the buyer escrowed.
+    // The platform fee is taken out of the seller's proceeds.
     let transfer_amount = buy_request.amount;
+    let sale_fee = ctx.accounts.fee_config.sale_fee(transfer_amount);
+    let seller_amount = transfer_amount - sale_fee;
+    let commission = consignment.map_or(0, |consignment| consignment.commission(seller_amount));
+
+    // An active lien is paid off before the seller sees anything
+    let payoff = match (car.lienholder, &ctx.accounts.lien, &ctx.accounts.lienholder) {
+        (Some(lender), Some(lien), Some(lienholder)) => {
+            require_keys_eq!(lien.lender, lender, LienError::LienAccountMissing);
+            require_keys_eq!(lienholder.key(), lender, LienError::LienAccountMissing);
+            require!(
+                lien.outstanding <= seller_amount - commission,
+                LienError::PayoffExceedsProceeds
+            );
+            lien.outstanding
+        }
+        (Some(_), _, _) => return err!(LienError::LienAccountMissing),
+        (None, _, _) => 0,
+    };
+    let proceeds = seller_amount - commission - payoff;
     
     **buy_request.to_account_info().try_borrow_mut_lamports()? -= transfer_amount;
-    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += transfer_amount;
+
+    // Co-owners are paid according to their shares
+    match &ctx.accounts.co_ownership {
+        Some(co_ownership) if car.is_co_owned => {
+            for (co_owner, share) in co_ownership.split(proceeds) {
+                let co_owner_account = ctx.remaining_accounts
+                    .iter()
+                    .find(|account| account.key == &co_owner && account.is_writable)
+                    .ok_or(CoOwnershipError::CoOwnerAccountMissing)?;
+                **co_owner_account.try_borrow_mut_lamports()? += share;
+            }
+        }
+        _ => {
+            **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += proceeds;
+        }
+    }
+
+    if let Some(lienholder) = &ctx.accounts.lienholder {
+        if payoff > 0 {
+            **lienholder.to_account_info().try_borrow_mut_lamports()? += payoff;
+        }
+    }
+
+    if let Some(dealer_wallet) = &ctx.accounts.dealer_wallet {
+        if commission > 0 {
+            **dealer_wallet.to_account_info().try_borrow_mut_lamports()? += commission;
+        }
+    }
+
+    if sale_fee > 0 {
+        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += sale_fee;
+        ctx.accounts.treasury.record_fee(sale_fee)?;
+
+        emit!(FeeCollected {
+            kind: FeeKind::Sale,
+            vin: vin.clone(),
+            payer: ctx.accounts.owner.key(),
+            amount: sale_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    // Move the escrowed transfer tax to the tax treasury and issue the buyer's receipt
+    let tax_amount = buy_request.tax_amount;
+    if tax_amount > 0 {
+        **buy_request.to_account_info().try_borrow_mut_lamports()? -= tax_amount;
+        **ctx.accounts.tax_treasury.to_account_info().try_borrow_mut_lamports()? += tax_amount;
+        ctx.accounts.tax_treasury.record_tax(tax_amount)?;
+    }
+
+    // The receipt and the ownership record share the car's transfer index
+    let vehicle_age = car.age_at(Clock::get()?.unix_timestamp);
+    let transfer_index = car.next_transfer_index()?;
+    ctx.accounts.tax_receipt.initialize(
+        transfer_index,
+        car.key(),
+        vin.clone(),
+        buyer,
+        car.owner,
+        Some(transfer_amount),
+        vehicle_age,
+        buy_request.tax_rate_bps,
+        tax_amount,
+        ctx.bumps.tax_receipt,
+    )?;
+
+    // Record the ownership change in the car's provenance chain
+    ctx.accounts.ownership_record.initialize(
+        car.key(),
+        transfer_index,
+        car.owner,
+        buyer,
+        Some(transfer_amount),
+        TransferMechanism::Sale,
+        ctx.bumps.ownership_record,
+    )?;
 
     // Transfer car ownership
     car.owner = buyer;
     car.is_for_sale = false;
     car.sale_price = None;
+    car.delegate = None;
+
+    // The consignment ends with the sale
+    if let (Some(consignment), Some(dealer)) = (&ctx.accounts.consignment, &mut ctx.accounts.dealer) {
+        if car.consigned_to.is_some() {
+            consignment.close(ctx.accounts.owner.to_account_info())?;
+            dealer.active_consignments = dealer.active_consignments.saturating_sub(1);
+            car.consigned_to = None;
+        }
+    }
+
+    // So does co-ownership
+    if let Some(co_ownership) = &ctx.accounts.co_ownership {
+        if car.is_co_owned {
+            co_ownership.close(ctx.accounts.owner.to_account_info())?;
+            car.is_co_owned = false;
+        }
+    }
+
+    // The lien is discharged by the payoff
+    if let (Some(lien), Some(lienholder)) = (&ctx.accounts.lien, &ctx.accounts.lienholder) {
+        if car.lienholder.is_some() {
+            lien.close(lienholder.to_account_info())?;
+            car.lienholder = None;
+        }
+    }
 
     // Update buy request status
     buy_request.status = BuyRequestStatus::Accepted;
 
     msg!("✅ Buy request accepted! Car {} transferred to {}", vin, buyer);
-    msg!("💰 Payment of {} lamports transferred to owner", buy_request.amount);
+    msg!(
+        "💰 Payment of {} lamports transferred to owner ({} lamports platform fee, {} lamports dealer commission, {} lamports lien payoff)",
+        proceeds,
+        sale_fee,
+        commission,
+        payoff
+    );
 
     Ok(())
 }
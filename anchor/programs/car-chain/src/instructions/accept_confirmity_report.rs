@@ -2,20 +2,28 @@ use anchor_lang::prelude::*;
 
 use crate::{
     state::confirmity_report::ConformityReport,
+    state::car_report::ReportStatus,
     state::car::CarAccount,
     state::user::{UserAccount, VerificationStatus, UserRoles},
-    errors::ConfirmityReportError,
+    errors::{ConfirmityReportError, CustomError, ReportStatusError},
+    utils::constants::GOVERNMENT_AUTHORITY,
 };
 
 #[derive(Accounts)]
-#[instruction(report_id: u64)]
+#[instruction(report_id: u64, vin: String, issuer: Pubkey)]
 pub struct AcceptConfirmityReport<'info> {
-    #[account(mut)]
+    // The report must be the one issued for this car by `issuer` under `report_id`
+    #[account(
+        mut,
+        seeds = [b"conformity_report", car.key().as_ref(), issuer.as_ref(), &report_id.to_le_bytes()],
+        bump = conformity_report.bump
+    )]
     pub conformity_report: Account<'info, ConformityReport>,
     
     #[account(
-        constraint = car.owner == owner.key(),
-        has_one = owner
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.owner == owner.key() @ CustomError::NotCarOwner
     )]
     pub car: Account<'info, CarAccount>,
     
@@ -23,7 +31,7 @@ pub struct AcceptConfirmityReport<'info> {
     pub owner: Signer<'info>
 }
 
-pub fn handler(ctx: Context<AcceptConfirmityReport>, report_id: u64) -> Result<()> {
+pub fn handler(ctx: Context<AcceptConfirmityReport>, _report_id: u64, _vin: String, _issuer: Pubkey) -> Result<()> {
     let report = &mut ctx.accounts.conformity_report;
     
     // Verify report belongs to the car
@@ -32,8 +40,10 @@ pub fn handler(ctx: Context<AcceptConfirmityReport>, report_id: u64) -> Result<(
         ConfirmityReportError::InvalidReport
     );
     
+    require!(report.status == ReportStatus::Pending, ReportStatusError::ReportNotPending);
+
     // Change report status to accepted
-    report.accepted_by_owner = true;
+    report.status = ReportStatus::Accepted;
     
     Ok(())
 } 
\ No newline at end of file
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, InstallmentPlan, InstallmentStatus, UserAccount, VerificationStatus};
+use crate::errors::{CarError, CustomError, InstallmentError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct AcceptInstallmentPlan<'info> {
+    #[account(
+        mut,
+        seeds = [b"installment_plan", car.key().as_ref()],
+        bump = installment_plan.bump,
+        has_one = buyer,
+        constraint = installment_plan.status == InstallmentStatus::Proposed @ InstallmentError::InvalidPlanStatus
+    )]
+    pub installment_plan: Account<'info, InstallmentPlan>,
+
+    #[account(
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.installment_plan == Some(installment_plan.key()) @ InstallmentError::InvalidPlanStatus,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [b"user", buyer.key().as_ref(), buyer_pda.user_name.as_bytes()],
+        bump = buyer_pda.bump,
+        constraint = buyer_pda.verification_status == VerificationStatus::Verified @ CustomError::UserNotVerified
+    )]
+    pub buyer_pda: Account<'info, UserAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AcceptInstallmentPlan>, _vin: String) -> Result<()> {
+    let down_payment = ctx.accounts.installment_plan.down_payment;
+
+    // The down payment is escrowed like every instalment
+    if down_payment > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.installment_plan.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, down_payment)?;
+    }
+
+    let plan = &mut ctx.accounts.installment_plan;
+    plan.amount_paid = down_payment;
+    plan.next_due_at = Clock::get()?.unix_timestamp
+        .checked_add(plan.interval_seconds)
+        .ok_or(InstallmentError::AmountOverflow)?;
+    plan.status = InstallmentStatus::Active;
+
+    msg!("📆 Instalment plan for car {} accepted, first instalment due {}", plan.vin, plan.next_due_at);
+
+    Ok(())
+}
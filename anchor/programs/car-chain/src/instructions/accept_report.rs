@@ -1,30 +1,49 @@
 use anchor_lang::prelude::*;
 
-use anchor_lang::prelude::*;
 use crate::{
-    state::car_report::CarReport,
+    state::car_report::{CarReport, ReportStatus},
+    state::inspection_config::InspectionConfig,
+    state::inspector_stats::InspectorStats,
     state::car::CarAccount,
     state::user::{UserAccount, VerificationStatus, UserRoles},
-    errors::CarReportError,
+    errors::{CarReportError, CustomError, ReportStatusError},
+    utils::constants::GOVERNMENT_AUTHORITY,
 };
 
 #[derive(Accounts)]
-#[instruction(report_id: u64)]
+#[instruction(report_id: u64, vin: String, issuer: Pubkey)]
 pub struct AcceptReport<'info> {
-    #[account(mut)]
+    // The report must be the one issued for this car by `issuer` under `report_id`
+    #[account(
+        mut,
+        seeds = [b"car_report", car.key().as_ref(), issuer.as_ref(), &report_id.to_le_bytes()],
+        bump = report.bump
+    )]
     pub report: Account<'info, CarReport>,
     
     #[account(
-        constraint = car.owner == owner.key(),
-        has_one = owner
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.owner == owner.key() @ CustomError::NotCarOwner
     )]
     pub car: Account<'info, CarAccount>,
     
     #[account(mut)]
-    pub owner: Signer<'info>
+    pub owner: Signer<'info>,
+
+    #[account(seeds = [b"inspection_config"], bump = inspection_config.bump)]
+    pub inspection_config: Account<'info, InspectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"inspector_stats", report.inspector.as_ref()],
+        bump = inspector_stats.bump
+    )]
+    pub inspector_stats: Account<'info, InspectorStats>,
 }
 
-pub fn handler(ctx: Context<AcceptReport>, report_id: u64) -> Result<()> {
+pub fn handler(ctx: Context<AcceptReport>, _report_id: u64, _vin: String, _issuer: Pubkey) -> Result<()> {
     let report = &mut ctx.accounts.report;
     
     // Verify report belongs to the car
@@ -33,8 +52,22 @@ pub fn handler(ctx: Context<AcceptReport>, report_id: u64) -> Result<()> {
         CarReportError::InvalidReport
     );
     
+    require!(report.status == ReportStatus::Pending, ReportStatusError::ReportNotPending);
+
     // Change report status to accepted
-    report.approved_by_owner = true;
+    report.status = ReportStatus::Accepted;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.car.apply_inspection_report(
+        report.key(),
+        report,
+        &ctx.accounts.inspection_config,
+        now,
+    );
+
+    let stats = &mut ctx.accounts.inspector_stats;
+    stats.reports_accepted = stats.reports_accepted.saturating_add(1);
+    stats.last_activity_at = now;
     
     Ok(())
 }
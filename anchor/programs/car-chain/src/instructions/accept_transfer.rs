@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CoOwnership, FeeKind, Organization, OwnershipRecord, PendingTransfer, TaxConfig, TaxReceipt, TaxTreasury, TransferMechanism, Treasury, UserAccount, VerificationStatus};
+use crate::errors::{CarError, CoOwnershipError, CustomError, DealerError, InstallmentError, LienError, TransferError};
+use crate::events::FeeCollected;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::acquiring_owner;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct AcceptTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"pending_transfer", car.key().as_ref()],
+        bump = pending_transfer.bump,
+        has_one = initiator,
+        close = initiator
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.owner == pending_transfer.previous_owner @ TransferError::OwnerChanged,
+        constraint = car.consigned_to.is_none() @ DealerError::CarConsigned,
+        constraint = car.lienholder.is_none() @ LienError::CarHasLien,
+        constraint = car.installment_plan.is_none() @ InstallmentError::CarUnderInstallmentPlan,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+        constraint = car.is_active @ CarError::CarDeregistered,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    /// CHECK: Receives the pending transfer rent back
+    #[account(mut)]
+    pub initiator: UncheckedAccount<'info>,
+
+    // The recipient, or an admin accepting for the recipient organization
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        seeds = [b"user", recipient.key().as_ref(), recipient_pda.user_name.as_bytes()],
+        bump = recipient_pda.bump,
+        constraint = recipient_pda.verification_status == VerificationStatus::Verified @ CustomError::UserNotVerified
+    )]
+    pub recipient_pda: Account<'info, UserAccount>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(seeds = [b"tax_config"], bump = tax_config.bump)]
+    pub tax_config: Account<'info, TaxConfig>,
+
+    #[account(mut, seeds = [b"tax_treasury"], bump = tax_treasury.bump)]
+    pub tax_treasury: Account<'info, TaxTreasury>,
+
+    #[account(
+        init,
+        payer = recipient,
+        space = TaxReceipt::LEN,
+        seeds = [b"tax_receipt", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub tax_receipt: Account<'info, TaxReceipt>,
+
+    #[account(
+        init,
+        payer = recipient,
+        space = OwnershipRecord::LEN,
+        seeds = [b"ownership_record", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub ownership_record: Account<'info, OwnershipRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    // Required when the car is transferred to an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AcceptTransfer>, vin: String) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now < ctx.accounts.pending_transfer.expires_at,
+        TransferError::TransferExpired
+    );
+    let new_owner = acquiring_owner(&ctx.accounts.recipient.key(), ctx.accounts.organization.as_ref())?;
+    require_keys_eq!(
+        new_owner,
+        ctx.accounts.pending_transfer.recipient,
+        TransferError::UnauthorizedRecipient
+    );
+
+    // Release the escrowed transfer fee to the treasury
+    let fee_amount = ctx.accounts.pending_transfer.fee_amount;
+    if fee_amount > 0 {
+        **ctx.accounts.pending_transfer.to_account_info().try_borrow_mut_lamports()? -= fee_amount;
+        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += fee_amount;
+        ctx.accounts.treasury.record_fee(fee_amount)?;
+
+        emit!(FeeCollected {
+            kind: FeeKind::Transfer,
+            vin: vin.clone(),
+            payer: ctx.accounts.initiator.key(),
+            amount: fee_amount,
+            timestamp: now,
+        });
+    }
+
+    // Transfer tax is paid by the recipient; without a sale price the flat rate applies
+    let tax_amount = ctx.accounts.tax_config.flat_transfer_tax;
+    if tax_amount > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.recipient.to_account_info(),
+                to: ctx.accounts.tax_treasury.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, tax_amount)?;
+        ctx.accounts.tax_treasury.record_tax(tax_amount)?;
+    }
+
+    let car = &mut ctx.accounts.car;
+    let transfer_index = car.next_transfer_index()?;
+    ctx.accounts.tax_receipt.initialize(
+        transfer_index,
+        car.key(),
+        vin.clone(),
+        new_owner,
+        car.owner,
+        None,
+        car.age_at(now),
+        0,
+        tax_amount,
+        ctx.bumps.tax_receipt,
+    )?;
+
+    ctx.accounts.ownership_record.initialize(
+        car.key(),
+        transfer_index,
+        car.owner,
+        new_owner,
+        None,
+        TransferMechanism::Gift,
+        ctx.bumps.ownership_record,
+    )?;
+
+    // Transfer ownership; listings and delegations belonged to the previous owner
+    car.owner = new_owner;
+    car.is_for_sale = false;
+    car.sale_price = None;
+    car.delegate = None;
+
+    // Co-ownership ends with the transfer
+    if car.is_co_owned {
+        let co_ownership = ctx.accounts.co_ownership
+            .as_ref()
+            .ok_or(CoOwnershipError::CoOwnershipAccountMissing)?;
+        co_ownership.close(ctx.accounts.initiator.to_account_info())?;
+        car.is_co_owned = false;
+    }
+
+    msg!("✅ Car with VIN {} has been transferred to {}", vin, car.owner);
+
+    Ok(())
+}
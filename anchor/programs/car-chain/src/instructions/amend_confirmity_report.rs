@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::confirmity_report::{ConformityAmendment, ConformityReport},
+    state::car_report::ReportStatus,
+    state::car::CarAccount,
+    state::user::{UserAccount, VerificationStatus, UserRoles},
+    errors::{ConfirmityReportError, DocumentError, ReportStatusError},
+};
+
+// See AmendReport
+#[derive(Accounts)]
+#[instruction(report_id: u64)]
+pub struct AmendConfirmityReport<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"conformity_report",
+            previous_report.car.as_ref(),
+            confirmity_expert_signer.key().as_ref(),
+            &previous_report.report_id.to_le_bytes()
+        ],
+        bump = previous_report.bump
+    )]
+    pub previous_report: Account<'info, ConformityReport>,
+
+    #[account(
+        init,
+        payer = confirmity_expert_signer,
+        space = ConformityReport::MAXIMUM_SIZE,
+        seeds = [
+            b"conformity_report",
+            car.key().as_ref(),
+            confirmity_expert_signer.key().as_ref(),
+            &report_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub conformity_report: Account<'info, ConformityReport>,
+
+    #[account(constraint = car.key() == previous_report.car @ ConfirmityReportError::InvalidReport)]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(
+        seeds = [b"user", confirmity_expert_signer.key().as_ref(), confirmity_expert.user_name.as_bytes()],
+        bump = confirmity_expert.bump,
+        constraint = confirmity_expert.role == UserRoles::ConfirmityExpert @ ConfirmityReportError::NotAuthorizedConfirmityExpert,
+        constraint = confirmity_expert.verification_status == VerificationStatus::Verified @ ConfirmityReportError::ConfirmityExpertNotVerified
+    )]
+    pub confirmity_expert: Account<'info, UserAccount>,
+
+    #[account(mut)]
+    pub confirmity_expert_signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AmendConfirmityReport>,
+    report_id: u64,
+    amendment: ConformityAmendment,
+) -> Result<()> {
+    let ConformityAmendment {
+        conformity_status,
+        modifications,
+        full_report_uri,
+        full_report_digest,
+        mines_stamp,
+        notes,
+    } = amendment;
+
+    require!(full_report_digest.is_valid(), DocumentError::InvalidDocumentDigest);
+    require!(modifications.len() <= 256, ConfirmityReportError::ModificationsTooLong);
+    require!(mines_stamp.len() <= 256, ConfirmityReportError::StampTooLong);
+    require!(notes.len() <= ConformityReport::MAX_NOTES_LENGTH, ConfirmityReportError::NotesTooLong);
+
+    let previous = &mut ctx.accounts.previous_report;
+    require!(
+        matches!(previous.status, ReportStatus::Pending | ReportStatus::Accepted),
+        ReportStatusError::ReportNotAmendable
+    );
+
+    let report = &mut ctx.accounts.conformity_report;
+    report.report_id = report_id;
+    report.car = previous.car;
+    report.confirmity_expert = ctx.accounts.confirmity_expert.key();
+    report.car_owner = ctx.accounts.car.owner;
+    report.report_date = previous.report_date;
+    report.conformity_status = conformity_status;
+    report.modifications = modifications;
+    report.full_report_uri = full_report_uri;
+    report.full_report_digest = full_report_digest;
+    report.mines_stamp = mines_stamp;
+    report.status = ReportStatus::Pending;
+    report.revision = previous.revision
+        .checked_add(1)
+        .ok_or(ReportStatusError::RevisionOverflow)?;
+    report.previous_revision = Some(previous.key());
+    report.superseded_by = None;
+    report.notes = notes;
+    report.bump = ctx.bumps.conformity_report;
+
+    previous.status = ReportStatus::Superseded;
+    previous.superseded_by = Some(report.key());
+
+    msg!("📝 Conformity report {} amended, revision {}", previous.key(), report.revision);
+
+    Ok(())
+}
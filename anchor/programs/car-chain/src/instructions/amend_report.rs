@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::car_report::{CarReport, ReportAmendment, ReportStatus},
+    state::car::{CarAccount, InspectionStatus},
+    state::inspector_stats::InspectorStats,
+    state::inspection_center::InspectionCenter,
+    state::user::{UserAccount, VerificationStatus, UserRoles},
+    errors::{CarReportError, DocumentError, InspectionCenterError, InspectionError, ReportStatusError},
+};
+
+// Issues a corrected revision of a report. The previous revision stays
+// readable, marked Superseded and linked to its replacement.
+#[derive(Accounts)]
+#[instruction(report_id: u64)]
+pub struct AmendReport<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"car_report",
+            previous_report.car.as_ref(),
+            inspector_signer.key().as_ref(),
+            &previous_report.report_id.to_le_bytes()
+        ],
+        bump = previous_report.bump
+    )]
+    pub previous_report: Account<'info, CarReport>,
+
+    #[account(
+        init,
+        payer = inspector_signer,
+        space = 8 + CarReport::MAXIMUM_SIZE,
+        seeds = [
+            b"car_report",
+            car.key().as_ref(),
+            inspector_signer.key().as_ref(),
+            &report_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub car_report: Account<'info, CarReport>,
+
+    #[account(mut, constraint = car.key() == previous_report.car @ CarReportError::InvalidReport)]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(
+        seeds = [b"user", inspector_signer.key().as_ref(), inspector.user_name.as_bytes()],
+        bump = inspector.bump,
+        constraint = inspector.role == UserRoles::Inspector @ CarReportError::NotAuthorizedInspector,
+        constraint = inspector.verification_status == VerificationStatus::Verified @ CarReportError::InspectorNotVerified
+    )]
+    pub inspector: Account<'info, UserAccount>,
+
+    #[account(mut)]
+    pub inspector_signer: Signer<'info>,
+
+    #[account(
+        seeds = [b"inspector_stats", inspector.key().as_ref()],
+        bump = inspector_stats.bump,
+        constraint = !inspector_stats.is_suspended() @ InspectionError::InspectorSuspended
+    )]
+    pub inspector_stats: Account<'info, InspectorStats>,
+
+    // Amendments go through the centre that issued the original report
+    #[account(
+        address = previous_report.inspection_center,
+        constraint = inspection_center.is_active() @ InspectionCenterError::CenterLicenceSuspended,
+        constraint = inspection_center.has_inspector(&inspector.key()) @ InspectionCenterError::InspectorNotMember
+    )]
+    pub inspection_center: Account<'info, InspectionCenter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<AmendReport>,
+    report_id: u64,
+    amendment: ReportAmendment,
+) -> Result<()> {
+    let ReportAmendment {
+        passed,
+        overall_condition,
+        engine_condition,
+        body_condition,
+        checklist,
+        full_report_uri,
+        full_report_digest,
+        report_summary,
+        notes,
+    } = amendment;
+
+    require!((1..=10).contains(&overall_condition), CarReportError::InvalidConditionScore);
+    require!((1..=10).contains(&engine_condition), CarReportError::InvalidConditionScore);
+    require!((1..=10).contains(&body_condition), CarReportError::InvalidConditionScore);
+    require!(notes.len() <= CarReport::MAX_NOTES_LENGTH, CarReportError::NotesTooLong);
+    require!(full_report_uri.len() <= CarReport::MAX_URI_LENGTH, CarReportError::UriTooLong);
+    require!(report_summary.len() <= CarReport::MAX_SUMMARY_LENGTH, CarReportError::SummaryTooLong);
+    require!(full_report_digest.is_valid(), DocumentError::InvalidDocumentDigest);
+    require!(checklist.is_valid(), CarReportError::InvalidChecklist);
+    require!(!(passed && checklist.has_failures()), CarReportError::ChecklistContradictsVerdict);
+
+    let previous = &mut ctx.accounts.previous_report;
+    require!(
+        matches!(previous.status, ReportStatus::Pending | ReportStatus::Accepted),
+        ReportStatusError::ReportNotAmendable
+    );
+
+    let report = &mut ctx.accounts.car_report;
+    report.report_id = report_id;
+    report.car = previous.car;
+    report.inspector = ctx.accounts.inspector.key();
+    report.inspection_center = previous.inspection_center;
+    report.car_owner = ctx.accounts.car.owner;
+    report.report_date = previous.report_date; // the inspection itself did not change
+    report.inspection_type = previous.inspection_type;
+    report.passed = passed;
+    report.overall_condition = overall_condition;
+    report.engine_condition = engine_condition;
+    report.body_condition = body_condition;
+    report.checklist = checklist;
+    report.full_report_uri = full_report_uri;
+    report.full_report_digest = full_report_digest;
+    report.report_summary = report_summary;
+    report.status = ReportStatus::Pending; // the owner has to accept the amended revision
+    report.revision = previous.revision
+        .checked_add(1)
+        .ok_or(ReportStatusError::RevisionOverflow)?;
+    report.previous_revision = Some(previous.key());
+    report.superseded_by = None;
+    report.notes = notes;
+    report.bump = ctx.bumps.car_report;
+
+    previous.status = ReportStatus::Superseded;
+    previous.superseded_by = Some(report.key());
+
+    // The car has no valid inspection until the owner accepts the amended revision
+    let car = &mut ctx.accounts.car;
+    if car.latest_inspection_report == Some(previous.key().to_string()) {
+        car.inspection_status = InspectionStatus::Pending;
+        car.inspection_expires_at = None;
+    }
+
+    msg!("📝 Report {} amended, revision {}", previous.key(), report.revision);
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::car::{CarAccount, CarDelegate, DelegateScope},
+    errors::{CarError, CoOwnershipError, CustomError},
+};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct ApproveDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"car",
+            GOVERNMENT_AUTHORITY.as_ref(),
+            vin.as_bytes()
+        ],
+        bump = car_account.bump,
+        constraint = !car_account.is_co_owned @ CoOwnershipError::CarCoOwned
+    )]
+    pub car_account: Account<'info, CarAccount>,
+
+    // Only the owner can grant or replace a delegation
+    #[account(
+        constraint = owner.key() == car_account.owner @ CustomError::NotCarOwner
+    )]
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ApproveDelegate>,
+    vin: String,
+    delegate: Pubkey,
+    scope: DelegateScope,
+    expires_at: i64,
+) -> Result<()> {
+    require!(
+        expires_at > Clock::get()?.unix_timestamp,
+        CarError::InvalidDelegateExpiry
+    );
+    require!(
+        scope.list || scope.accept_offers || scope.transfer,
+        CarError::EmptyDelegateScope
+    );
+
+    let car_account = &mut ctx.accounts.car_account;
+    car_account.delegate = Some(CarDelegate {
+        delegate,
+        scope,
+        expires_at,
+    });
+
+    msg!("Car {} delegated to {} until {}", vin, delegate, expires_at);
+
+    Ok(())
+}
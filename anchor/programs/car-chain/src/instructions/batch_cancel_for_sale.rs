@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, DealerAccount};
+use crate::errors::DealerError;
+
+// Cars to delist are passed as writable remaining accounts
+#[derive(Accounts)]
+pub struct BatchCancelForSale<'info> {
+    #[account(
+        seeds = [b"dealer", dealer.authority.as_ref()],
+        bump = dealer.bump,
+        constraint = dealer.is_authorized(&authority.key()) @ DealerError::UnauthorizedDealerStaff
+    )]
+    pub dealer: Account<'info, DealerAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchCancelForSale<'info>>,
+) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len() <= DealerAccount::MAX_BATCH_SIZE,
+        DealerError::BatchTooLarge
+    );
+
+    let dealer_key = ctx.accounts.dealer.key();
+
+    for car_info in ctx.remaining_accounts.iter() {
+        require!(car_info.is_writable, DealerError::CarNotWritable);
+
+        let mut car = Account::<CarAccount>::try_from(car_info)?;
+        require!(car.consigned_to == Some(dealer_key), DealerError::CarNotConsigned);
+
+        car.is_for_sale = false;
+        car.sale_price = None;
+        car.exit(&crate::ID)?;
+
+        msg!("Car {} has been removed from sale", car.vin);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, DealerAccount, InspectionConfig};
+use crate::errors::{CarError, DealerError, InspectionError, InstallmentError};
+
+// Cars to list are passed as writable remaining accounts, in the same order as `prices`
+#[derive(Accounts)]
+pub struct BatchSetForSale<'info> {
+    #[account(
+        seeds = [b"dealer", dealer.authority.as_ref()],
+        bump = dealer.bump,
+        constraint = dealer.is_authorized(&authority.key()) @ DealerError::UnauthorizedDealerStaff
+    )]
+    pub dealer: Account<'info, DealerAccount>,
+
+    #[account(seeds = [b"inspection_config"], bump = inspection_config.bump)]
+    pub inspection_config: Account<'info, InspectionConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchSetForSale<'info>>,
+    prices: Vec<u64>,
+) -> Result<()> {
+    require!(prices.len() <= DealerAccount::MAX_BATCH_SIZE, DealerError::BatchTooLarge);
+    require!(prices.len() == ctx.remaining_accounts.len(), DealerError::BatchLengthMismatch);
+
+    let dealer_key = ctx.accounts.dealer.key();
+    let require_valid_inspection = ctx.accounts.inspection_config.require_valid_inspection_for_sale;
+    let now = Clock::get()?.unix_timestamp;
+
+    for (car_info, price) in ctx.remaining_accounts.iter().zip(prices) {
+        require!(car_info.is_writable, DealerError::CarNotWritable);
+
+        let mut car = Account::<CarAccount>::try_from(car_info)?;
+        require!(car.consigned_to == Some(dealer_key), DealerError::CarNotConsigned);
+        require!(!car.is_for_sale, CarError::CarAlreadyForSale);
+        require!(car.installment_plan.is_none(), InstallmentError::CarUnderInstallmentPlan);
+        require!(!car.is_stolen, CarError::CarReportedStolen);
+        require!(car.is_active, CarError::CarDeregistered);
+        require!(
+            !require_valid_inspection || car.has_valid_inspection(now),
+            InspectionError::ValidInspectionRequired
+        );
+
+        car.is_for_sale = true;
+        car.sale_price = Some(price);
+        car.listing_version = car.listing_version
+            .checked_add(1)
+            .ok_or(CarError::ListingVersionOverflow)?;
+        car.exit(&crate::ID)?;
+
+        msg!("Car {} has been set for sale at {} lamports", car.vin, price);
+    }
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 use crate::{
-    state::car::{CarAccount},
+    state::car::{CarAccount, DelegateAction},
+    state::co_ownership::CoOwnership,
+    state::organization::Organization,
     errors::CustomError,
 };
 use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::is_car_action_authorized;
 
 #[derive(Accounts)]
 #[instruction(vin: String)]
@@ -20,12 +23,23 @@ pub struct CancelCarForSale<'info> {
     )]
     pub car_account: Account<'info, CarAccount>,
     
-    // The owner of the car
+    // The owner of the car, or a delegate allowed to list it
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Required when the car is co-owned; other co-owners sign as remaining accounts
     #[account(
-        mut,
-        constraint = owner.key() == car_account.owner @ CustomError::NotCarOwner
+        seeds = [b"co_ownership", car_account.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    // Required when the car is owned by an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
     )]
-    pub owner: Signer<'info>,
+    pub organization: Option<Account<'info, Organization>>,
 
     system_program: Program<'info, System>,
 }
@@ -35,6 +49,19 @@ pub fn handler(
     vin: String,
 ) -> Result<()> {
     let car_account = &mut ctx.accounts.car_account;
+
+    require!(
+        is_car_action_authorized(
+            car_account,
+            ctx.accounts.co_ownership.as_deref(),
+            ctx.accounts.organization.as_ref(),
+            &ctx.accounts.authority.key(),
+            ctx.remaining_accounts,
+            Clock::get()?.unix_timestamp,
+            DelegateAction::List
+        )?,
+        CustomError::NotCarOwner
+    );
     
     // Remove car from sale
     car_account.is_for_sale = false;
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, InspectionRequest, InspectionRequestStatus};
+use crate::errors::InspectionError;
+
+#[derive(Accounts)]
+pub struct CancelInspectionRequest<'info> {
+    // Closing returns the escrowed fee and rent to the owner who booked
+    #[account(
+        mut,
+        seeds = [b"inspection_request", car.key().as_ref()],
+        bump = inspection_request.bump,
+        has_one = owner,
+        close = owner
+    )]
+    pub inspection_request: Account<'info, InspectionRequest>,
+
+    pub car: Account<'info, CarAccount>,
+
+    /// CHECK: The owner who booked and paid for the inspection
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    // The owner who booked, or the car's current owner once it has changed hands
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelInspectionRequest>) -> Result<()> {
+    let request = &ctx.accounts.inspection_request;
+    let authority = ctx.accounts.authority.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    // A claimed request can only be withdrawn once the inspector has missed the window
+    let booker_cancels = authority == request.owner
+        && (request.status == InspectionRequestStatus::Open || now > request.window_end);
+    // A booking left behind by a previous owner blocks new ones; the current
+    // owner may clear it at any time, and the fee still goes back to whoever paid it
+    let new_owner_clears = ctx.accounts.car.owner != request.owner
+        && authority == ctx.accounts.car.owner;
+    require!(booker_cancels || new_owner_clears, InspectionError::CannotCancelInspection);
+
+    msg!("❌ Inspection request for car {} cancelled, {} lamports refunded", request.car, request.fee);
+
+    Ok(())
+}
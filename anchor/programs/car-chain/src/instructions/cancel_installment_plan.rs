@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, InstallmentPlan, InstallmentStatus};
+use crate::errors::InstallmentError;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct CancelInstallmentPlan<'info> {
+    #[account(
+        mut,
+        seeds = [b"installment_plan", car.key().as_ref()],
+        bump = installment_plan.bump,
+        has_one = buyer,
+        has_one = seller,
+    )]
+    pub installment_plan: Account<'info, InstallmentPlan>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.installment_plan == Some(installment_plan.key()) @ InstallmentError::InvalidPlanStatus,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    /// CHECK: The buyer (refunded in full)
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// CHECK: The seller (receives the plan account's rent)
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelInstallmentPlan>, vin: String) -> Result<()> {
+    let plan = &ctx.accounts.installment_plan;
+    let car = &mut ctx.accounts.car;
+    let authority = ctx.accounts.authority.key();
+
+    // The seller can withdraw a plan the buyer has not accepted yet. Either
+    // party can walk away if the seller no longer owns the car, since the
+    // title can then never be delivered.
+    let seller_withdraws = authority == plan.seller && plan.status == InstallmentStatus::Proposed;
+    let title_lost = car.owner != plan.seller && (authority == plan.seller || authority == plan.buyer);
+    require!(seller_withdraws || title_lost, InstallmentError::CannotCancelPlan);
+
+    let refund = plan.amount_paid;
+    if refund > 0 {
+        **plan.to_account_info().try_borrow_mut_lamports()? -= refund;
+        **ctx.accounts.buyer.to_account_info().try_borrow_mut_lamports()? += refund;
+    }
+
+    car.installment_plan = None;
+    ctx.accounts.installment_plan.close(ctx.accounts.seller.to_account_info())?;
+
+    msg!("❌ Instalment plan for car {} cancelled, {} lamports refunded to the buyer", vin, refund);
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CoOwnership, DelegateAction, Organization, PendingTransfer};
+use crate::errors::TransferError;
+use crate::utils::validation::is_car_action_authorized;
+
+#[derive(Accounts)]
+pub struct CancelTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"pending_transfer", car.key().as_ref()],
+        bump = pending_transfer.bump,
+        has_one = initiator,
+        close = initiator
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+
+    pub car: Account<'info, CarAccount>,
+
+    /// CHECK: Refunded the escrowed fee and rent
+    #[account(mut)]
+    pub initiator: UncheckedAccount<'info>,
+
+    // The initiator, or whoever could initiate a transfer of the car now
+    pub authority: Signer<'info>,
+
+    // Required when the car is co-owned; other co-owners sign as remaining accounts
+    #[account(
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    // Required when the car is owned by an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+pub fn handler(ctx: Context<CancelTransfer>) -> Result<()> {
+    let pending_transfer = &ctx.accounts.pending_transfer;
+    let now = Clock::get()?.unix_timestamp;
+
+    // The initiator and the owner side may cancel at any time, so an owner can
+    // withdraw a transfer started by a delegate or fleet manager; once expired
+    // anyone may clean it up so the car can be transferred again
+    require!(
+        ctx.accounts.authority.key() == pending_transfer.initiator
+            || now >= pending_transfer.expires_at
+            || is_car_action_authorized(
+                &ctx.accounts.car,
+                ctx.accounts.co_ownership.as_deref(),
+                ctx.accounts.organization.as_ref(),
+                &ctx.accounts.authority.key(),
+                ctx.remaining_accounts,
+                now,
+                DelegateAction::Transfer
+            )?,
+        TransferError::UnauthorizedInitiator
+    );
+
+    msg!("Transfer of car {} cancelled", pending_transfer.vin);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    CarAccount, InspectionCenter, InspectionRequest, InspectionRequestStatus, InspectorStats, UserAccount, UserRoles,
+    VerificationStatus,
+};
+use crate::errors::{CarReportError, InspectionCenterError, InspectionError};
+
+#[derive(Accounts)]
+pub struct ClaimInspection<'info> {
+    #[account(
+        mut,
+        seeds = [b"inspection_request", car.key().as_ref()],
+        bump = inspection_request.bump,
+        constraint = inspection_request.status == InspectionRequestStatus::Open @ InspectionError::InspectionRequestNotOpen
+    )]
+    pub inspection_request: Account<'info, InspectionRequest>,
+
+    // A booking made before the car changed hands is no longer the owner's
+    #[account(
+        constraint = car.owner == inspection_request.owner @ InspectionError::StaleInspectionRequest
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(
+        seeds = [b"user", inspector_signer.key().as_ref(), inspector.user_name.as_bytes()],
+        bump = inspector.bump,
+        constraint = inspector.role == UserRoles::Inspector @ CarReportError::NotAuthorizedInspector,
+        constraint = inspector.verification_status == VerificationStatus::Verified @ CarReportError::InspectorNotVerified
+    )]
+    pub inspector: Account<'info, UserAccount>,
+
+    #[account(mut)]
+    pub inspector_signer: Signer<'info>,
+
+    // A suspended inspector cannot take on new bookings
+    #[account(
+        init_if_needed,
+        payer = inspector_signer,
+        space = InspectorStats::LEN,
+        seeds = [b"inspector_stats", inspector.key().as_ref()],
+        bump,
+        constraint = !inspector_stats.is_suspended() @ InspectionError::InspectorSuspended
+    )]
+    pub inspector_stats: Account<'info, InspectorStats>,
+
+    // The centre the inspector will report through must be able to take the booking
+    #[account(
+        constraint = inspection_center.is_active() @ InspectionCenterError::CenterLicenceSuspended,
+        constraint = inspection_center.has_inspector(&inspector.key()) @ InspectionCenterError::InspectorNotMember,
+        constraint = inspection_center.approves(inspection_request.requested_type) @ InspectionCenterError::InspectionTypeNotApproved
+    )]
+    pub inspection_center: Account<'info, InspectionCenter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ClaimInspection>) -> Result<()> {
+    let request = &mut ctx.accounts.inspection_request;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now <= request.window_end, InspectionError::InspectionWindowClosed);
+
+    request.inspector = Some(ctx.accounts.inspector_signer.key());
+    request.status = InspectionRequestStatus::Claimed;
+    request.claimed_at = Some(now);
+
+    let stats = &mut ctx.accounts.inspector_stats;
+    stats.inspector = ctx.accounts.inspector.key();
+    stats.bump = ctx.bumps.inspector_stats;
+
+    msg!("🔍 Inspection for car {} claimed by {}", request.car, ctx.accounts.inspector_signer.key());
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, InstallmentPlan};
+use crate::errors::{CarError, InstallmentError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct ClaimInstallmentDefault<'info> {
+    #[account(
+        mut,
+        seeds = [b"installment_plan", car.key().as_ref()],
+        bump = installment_plan.bump,
+        has_one = buyer,
+        has_one = seller,
+    )]
+    pub installment_plan: Account<'info, InstallmentPlan>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.installment_plan == Some(installment_plan.key()) @ InstallmentError::InvalidPlanStatus,
+        constraint = car.owner == seller.key() @ CarError::UnauthorizedAccess,
+        // The buyer cannot pay while the car is flagged stolen
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: This is the buyer (for receiving the partial refund)
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ClaimInstallmentDefault>, vin: String) -> Result<()> {
+    let plan = &ctx.accounts.installment_plan;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(plan.is_in_default(now), InstallmentError::NotInDefault);
+
+    // The buyer gets the agreed share of what they paid back; the seller
+    // keeps the rest along with the car
+    let refund = plan.default_refund();
+    let retained = plan.amount_paid - refund;
+
+    **plan.to_account_info().try_borrow_mut_lamports()? -= plan.amount_paid;
+    **ctx.accounts.buyer.to_account_info().try_borrow_mut_lamports()? += refund;
+    **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += retained;
+
+    ctx.accounts.car.installment_plan = None;
+    ctx.accounts.installment_plan.close(ctx.accounts.seller.to_account_info())?;
+
+    msg!("⚠️ Instalment plan for car {} defaulted", vin);
+    msg!("💰 {} lamports refunded to the buyer, {} lamports retained by the seller", refund, retained);
+
+    Ok(())
+}
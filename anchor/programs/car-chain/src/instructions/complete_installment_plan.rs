@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, FeeConfig, FeeKind, InstallmentPlan, InstallmentStatus, OwnershipRecord, TransferMechanism, Treasury};
+use crate::errors::{CarError, InstallmentError};
+use crate::events::FeeCollected;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct CompleteInstallmentPlan<'info> {
+    #[account(
+        mut,
+        seeds = [b"installment_plan", car.key().as_ref()],
+        bump = installment_plan.bump,
+        has_one = buyer,
+        has_one = seller,
+        constraint = installment_plan.status == InstallmentStatus::Active @ InstallmentError::InvalidPlanStatus,
+        constraint = installment_plan.is_fully_paid() @ InstallmentError::PlanNotFullyPaid
+    )]
+    pub installment_plan: Account<'info, InstallmentPlan>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.installment_plan == Some(installment_plan.key()) @ InstallmentError::InvalidPlanStatus,
+        constraint = car.owner == seller.key() @ CarError::UnauthorizedAccess,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+        constraint = car.is_active @ CarError::CarDeregistered,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: This is the seller (for receiving the escrowed payments)
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"fee_config"], bump = fee_config.bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = OwnershipRecord::LEN,
+        seeds = [b"ownership_record", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub ownership_record: Account<'info, OwnershipRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CompleteInstallmentPlan>, vin: String) -> Result<()> {
+    let plan = &ctx.accounts.installment_plan;
+    let car = &mut ctx.accounts.car;
+
+    // The platform fee is taken out of the seller's proceeds, as for a direct sale
+    let sale_fee = ctx.accounts.fee_config.sale_fee(plan.amount_paid);
+    let proceeds = plan.amount_paid - sale_fee;
+
+    **plan.to_account_info().try_borrow_mut_lamports()? -= plan.amount_paid;
+    **ctx.accounts.seller.to_account_info().try_borrow_mut_lamports()? += proceeds;
+
+    if sale_fee > 0 {
+        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += sale_fee;
+        ctx.accounts.treasury.record_fee(sale_fee)?;
+
+        emit!(FeeCollected {
+            kind: FeeKind::Sale,
+            vin: vin.clone(),
+            payer: plan.seller,
+            amount: sale_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    // Record the ownership change in the car's provenance chain
+    let transfer_index = car.next_transfer_index()?;
+    ctx.accounts.ownership_record.initialize(
+        car.key(),
+        transfer_index,
+        plan.seller,
+        plan.buyer,
+        Some(plan.total_price),
+        TransferMechanism::Installment,
+        ctx.bumps.ownership_record,
+    )?;
+
+    // Title passes to the buyer only now that the last instalment is in
+    car.owner = plan.buyer;
+    car.is_for_sale = false;
+    car.sale_price = None;
+    car.delegate = None;
+    car.installment_plan = None;
+
+    ctx.accounts.installment_plan.close(ctx.accounts.seller.to_account_info())?;
+
+    msg!("✅ Instalment plan completed! Car {} transferred to {}", vin, car.owner);
+    msg!("💰 {} lamports paid to the seller ({} lamports platform fee)", proceeds, sale_fee);
+
+    Ok(())
+}
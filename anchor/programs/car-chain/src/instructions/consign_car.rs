@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, Consignment, DealerAccount};
+use crate::errors::{CarError, CoOwnershipError, CustomError, DealerError, InstallmentError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct ConsignCar<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = Consignment::LEN,
+        seeds = [b"consignment", car.key().as_ref()],
+        bump
+    )]
+    pub consignment: Account<'info, Consignment>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.owner == owner.key() @ CustomError::NotCarOwner,
+        constraint = car.consigned_to.is_none() @ DealerError::CarConsigned,
+        constraint = !car.is_co_owned @ CoOwnershipError::CarCoOwned,
+        constraint = car.installment_plan.is_none() @ InstallmentError::CarUnderInstallmentPlan,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+        constraint = car.is_active @ CarError::CarDeregistered,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"dealer", dealer.authority.as_ref()],
+        bump = dealer.bump
+    )]
+    pub dealer: Account<'info, DealerAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ConsignCar>, vin: String, commission_bps: u16) -> Result<()> {
+    require!(commission_bps <= Consignment::MAX_BPS, DealerError::InvalidCommission);
+
+    let car = &mut ctx.accounts.car;
+    let dealer = &mut ctx.accounts.dealer;
+    let consignment = &mut ctx.accounts.consignment;
+
+    consignment.car = car.key();
+    consignment.owner = car.owner;
+    consignment.dealer = dealer.key();
+    consignment.commission_bps = commission_bps;
+    consignment.created_at = Clock::get()?.unix_timestamp;
+    consignment.bump = ctx.bumps.consignment;
+
+    car.consigned_to = Some(dealer.key());
+    dealer.active_consignments = dealer.active_consignments.saturating_add(1);
+
+    msg!("🏪 Car {} consigned to dealer {} at {} bps commission", vin, dealer.key(), commission_bps);
+
+    Ok(())
+}
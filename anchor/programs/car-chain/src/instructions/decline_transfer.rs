@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{Organization, PendingTransfer};
+use crate::errors::TransferError;
+use crate::utils::validation::acquiring_owner;
+
+#[derive(Accounts)]
+pub struct DeclineTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"pending_transfer", pending_transfer.car.as_ref()],
+        bump = pending_transfer.bump,
+        has_one = initiator,
+        close = initiator
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+
+    /// CHECK: Refunded the escrowed fee and rent
+    #[account(mut)]
+    pub initiator: UncheckedAccount<'info>,
+
+    // The recipient, or an admin declining for the recipient organization
+    pub recipient: Signer<'info>,
+
+    // Required when the car was offered to an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+pub fn handler(ctx: Context<DeclineTransfer>) -> Result<()> {
+    let recipient = acquiring_owner(&ctx.accounts.recipient.key(), ctx.accounts.organization.as_ref())?;
+    require_keys_eq!(
+        recipient,
+        ctx.accounts.pending_transfer.recipient,
+        TransferError::UnauthorizedRecipient
+    );
+
+    msg!("❌ Transfer of car {} declined", ctx.accounts.pending_transfer.vin);
+
+    Ok(())
+}
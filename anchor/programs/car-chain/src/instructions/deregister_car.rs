@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CoOwnership, DeregistrationReason, DeregistrationRecord, DocumentDigest};
+use crate::errors::{CarError, CoOwnershipError, CustomError, DealerError, DeregistrationError, DocumentError, InstallmentError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct DeregisterCar<'info> {
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.is_active @ CarError::CarDeregistered,
+        constraint = car.consigned_to.is_none() @ DealerError::CarConsigned,
+        constraint = car.installment_plan.is_none() @ InstallmentError::CarUnderInstallmentPlan,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(
+        init,
+        payer = government,
+        space = DeregistrationRecord::LEN,
+        seeds = [b"deregistration", car.key().as_ref(), &car.registration_count.to_le_bytes()],
+        bump
+    )]
+    pub deregistration_record: Account<'info, DeregistrationRecord>,
+
+    // Required when the car is co-owned; deregistration dissolves it
+    #[account(
+        mut,
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    /// CHECK: The registered owner, refunded the co-ownership rent
+    #[account(mut, address = car.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<DeregisterCar>,
+    vin: String,
+    reason: DeregistrationReason,
+    certificate_uri: String,
+    certificate_digest: DocumentDigest,
+) -> Result<()> {
+    require!(certificate_digest.is_valid(), DocumentError::InvalidDocumentDigest);
+    require!(
+        !certificate_uri.is_empty() && certificate_uri.len() <= DeregistrationRecord::MAX_CERTIFICATE_URI_LEN,
+        DeregistrationError::InvalidCertificate
+    );
+
+    if ctx.accounts.car.is_co_owned {
+        let co_ownership = ctx.accounts.co_ownership
+            .as_ref()
+            .ok_or(CoOwnershipError::CoOwnershipAccountMissing)?;
+        co_ownership.close(ctx.accounts.owner.to_account_info())?;
+        ctx.accounts.car.is_co_owned = false;
+    }
+
+    let car = &mut ctx.accounts.car;
+    let record = &mut ctx.accounts.deregistration_record;
+    let now = Clock::get()?.unix_timestamp;
+
+    record.car = car.key();
+    record.vin = vin.clone();
+    record.registration_number = car.registration_count;
+    record.reason = reason;
+    record.certificate_uri = certificate_uri;
+    record.certificate_digest = certificate_digest;
+    record.owner = car.owner;
+    record.deregistered_by = ctx.accounts.government.key();
+    record.deregistered_at = now;
+    record.reregistered_at = None;
+    record.bump = ctx.bumps.deregistration_record;
+
+    // A deregistered car leaves the market; open buy requests become reclaimable
+    car.is_active = false;
+    car.is_for_sale = false;
+    car.sale_price = None;
+    car.delegate = None;
+    car.last_deregistration = Some(record.key());
+
+    msg!("🗑️ Car {} deregistered", vin);
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CarReport, ConformityReport, DocumentDigest, ReportDispute, ReportStatus};
+use crate::errors::{CustomError, DocumentError, ReportStatusError};
+
+// Either `car_report` or `conformity_report` is given, never both, and
+// `report` is its address
+#[derive(Accounts)]
+#[instruction(report: Pubkey)]
+pub struct DisputeReport<'info> {
+    #[account(mut)]
+    pub car_report: Option<Account<'info, CarReport>>,
+
+    #[account(mut)]
+    pub conformity_report: Option<Account<'info, ConformityReport>>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ReportDispute::LEN,
+        seeds = [b"report_dispute", report.as_ref()],
+        bump
+    )]
+    pub report_dispute: Account<'info, ReportDispute>,
+
+    #[account(constraint = car.owner == owner.key() @ CustomError::NotCarOwner)]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<DisputeReport>,
+    report: Pubkey,
+    reason: String,
+    evidence_uri: String,
+    evidence_digest: DocumentDigest,
+) -> Result<()> {
+    require!(evidence_digest.is_valid(), DocumentError::InvalidDocumentDigest);
+    require!(reason.len() <= ReportDispute::MAX_REASON_LEN, ReportStatusError::DisputeReasonTooLong);
+    require!(
+        !evidence_uri.is_empty() && evidence_uri.len() <= ReportDispute::MAX_EVIDENCE_URI_LEN,
+        ReportStatusError::InvalidEvidenceUri
+    );
+
+    let car_key = ctx.accounts.car.key();
+    match (&mut ctx.accounts.car_report, &mut ctx.accounts.conformity_report) {
+        (Some(car_report), None) => {
+            require_keys_eq!(car_report.key(), report, ReportStatusError::InvalidReportAccounts);
+            require_keys_eq!(car_report.car, car_key, ReportStatusError::InvalidReportAccounts);
+            require!(car_report.status == ReportStatus::Pending, ReportStatusError::ReportNotPending);
+            car_report.status = ReportStatus::Disputed;
+        }
+        (None, Some(conformity_report)) => {
+            require_keys_eq!(conformity_report.key(), report, ReportStatusError::InvalidReportAccounts);
+            require_keys_eq!(conformity_report.car, car_key, ReportStatusError::InvalidReportAccounts);
+            require!(conformity_report.status == ReportStatus::Pending, ReportStatusError::ReportNotPending);
+            conformity_report.status = ReportStatus::Disputed;
+        }
+        _ => return err!(ReportStatusError::InvalidReportAccounts),
+    }
+
+    let dispute = &mut ctx.accounts.report_dispute;
+    dispute.report = report;
+    dispute.car = car_key;
+    dispute.disputed_by = ctx.accounts.owner.key();
+    dispute.reason = reason;
+    dispute.evidence_uri = evidence_uri;
+    dispute.evidence_digest = evidence_digest;
+    dispute.created_at = Clock::get()?.unix_timestamp;
+    dispute.resolution = None;
+    dispute.resolved_by = None;
+    dispute.resolved_at = None;
+    dispute.bump = ctx.bumps.report_dispute;
+
+    msg!("⚖️ Report {} disputed by the owner", report);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CoOwnership};
+use crate::errors::CoOwnershipError;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+// Co-owners other than the authority sign as remaining accounts
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct DissolveCoOwnership<'info> {
+    #[account(
+        mut,
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump,
+        close = owner
+    )]
+    pub co_ownership: Account<'info, CoOwnership>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.is_co_owned @ CoOwnershipError::CarNotCoOwned,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    /// CHECK: The primary co-owner, who becomes sole owner and is refunded the rent
+    #[account(mut, address = car.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<DissolveCoOwnership>, vin: String) -> Result<()> {
+    require!(
+        ctx.accounts.co_ownership.approves(&ctx.accounts.authority.key(), ctx.remaining_accounts),
+        CoOwnershipError::InsufficientApproval
+    );
+
+    ctx.accounts.car.is_co_owned = false;
+
+    msg!("Co-ownership of car {} dissolved", vin);
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CoOwner, CoOwnership};
+use crate::errors::{CoOwnershipError, CustomError, DealerError, InstallmentError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct EnableCoOwnership<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = CoOwnership::LEN,
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump
+    )]
+    pub co_ownership: Account<'info, CoOwnership>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.owner == owner.key() @ CustomError::NotCarOwner,
+        constraint = !car.is_co_owned @ CoOwnershipError::CarCoOwned,
+        constraint = car.consigned_to.is_none() @ DealerError::CarConsigned,
+        constraint = car.installment_plan.is_none() @ InstallmentError::CarUnderInstallmentPlan,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<EnableCoOwnership>,
+    vin: String,
+    owners: Vec<CoOwner>,
+    approval_threshold_bps: u16,
+) -> Result<()> {
+    require!(
+        owners.len() >= 2 && owners.len() <= CoOwnership::MAX_OWNERS,
+        CoOwnershipError::InvalidOwnerCount
+    );
+    require!(
+        owners.iter().enumerate().all(|(index, co_owner)| {
+            owners[..index].iter().all(|other| other.owner != co_owner.owner)
+        }),
+        CoOwnershipError::InvalidOwnerCount
+    );
+    require!(
+        owners.iter().all(|co_owner| co_owner.share_bps > 0)
+            && owners.iter().map(|co_owner| co_owner.share_bps as u32).sum::<u32>()
+                == CoOwnership::TOTAL_BPS as u32,
+        CoOwnershipError::InvalidShares
+    );
+    require!(
+        approval_threshold_bps > CoOwnership::TOTAL_BPS / 2
+            && approval_threshold_bps <= CoOwnership::TOTAL_BPS,
+        CoOwnershipError::InvalidThreshold
+    );
+
+    let car = &mut ctx.accounts.car;
+    require!(
+        owners.iter().any(|co_owner| co_owner.owner == car.owner),
+        CoOwnershipError::OwnerNotCoOwner
+    );
+
+    // Nobody takes on a share without agreeing to it; the other co-owners
+    // sign the transaction as remaining accounts
+    require!(
+        owners.iter().all(|co_owner| {
+            co_owner.owner == car.owner
+                || ctx.remaining_accounts
+                    .iter()
+                    .any(|account| account.is_signer && account.key == &co_owner.owner)
+        }),
+        CoOwnershipError::CoOwnerSignatureMissing
+    );
+
+    let co_ownership = &mut ctx.accounts.co_ownership;
+    co_ownership.car = car.key();
+    co_ownership.owners = owners;
+    co_ownership.approval_threshold_bps = approval_threshold_bps;
+    co_ownership.created_at = Clock::get()?.unix_timestamp;
+    co_ownership.bump = ctx.bumps.co_ownership;
+
+    // The owner stays on record as the primary co-owner, but can no longer act
+    // alone; a sole owner's delegation does not carry over
+    car.is_co_owned = true;
+    car.delegate = None;
+
+    msg!("👥 Car {} is now co-owned by {} owners", vin, co_ownership.owners.len());
+
+    Ok(())
+}
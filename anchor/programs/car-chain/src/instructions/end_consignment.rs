@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, Consignment, DealerAccount};
+use crate::errors::DealerError;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct EndConsignment<'info> {
+    #[account(
+        mut,
+        seeds = [b"consignment", car.key().as_ref()],
+        bump = consignment.bump,
+        has_one = owner,
+        has_one = dealer,
+        close = owner
+    )]
+    pub consignment: Account<'info, Consignment>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.consigned_to == Some(dealer.key()) @ DealerError::CarNotConsigned
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub dealer: Account<'info, DealerAccount>,
+
+    /// CHECK: The car owner, refunded the consignment rent
+    #[account(mut, address = car.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    // Either the owner or the dealer's staff may end a consignment
+    #[account(
+        constraint = authority.key() == owner.key()
+            || dealer.is_authorized(&authority.key()) @ DealerError::UnauthorizedDealerStaff
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<EndConsignment>, vin: String) -> Result<()> {
+    let car = &mut ctx.accounts.car;
+
+    // A dealer listing does not survive the consignment
+    car.consigned_to = None;
+    car.is_for_sale = false;
+    car.sale_price = None;
+
+    let dealer = &mut ctx.accounts.dealer;
+    dealer.active_consignments = dealer.active_consignments.saturating_sub(1);
+
+    msg!("Consignment of car {} ended", vin);
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    BuyRequest, BuyRequestStatus, CarAccount, CoOwnership, Consignment, DealerAccount, DocumentDigest,
+    ForcedTransferReason, ForcedTransferRecord, InstallmentPlan, OwnershipRecord, TransferMechanism,
+};
+use crate::errors::{
+    CarError, CoOwnershipError, CustomError, DealerError, DocumentError, InstallmentError, TransferError,
+};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+// Open buy requests for the car are passed as remaining accounts in
+// (buy_request, buyer) pairs and are closed with a full refund to the buyer.
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct ForceTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.is_active @ CarError::CarDeregistered,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(
+        init,
+        payer = government,
+        space = OwnershipRecord::LEN,
+        seeds = [b"ownership_record", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub ownership_record: Account<'info, OwnershipRecord>,
+
+    #[account(
+        init,
+        payer = government,
+        space = ForcedTransferRecord::LEN,
+        seeds = [b"forced_transfer", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub forced_transfer_record: Account<'info, ForcedTransferRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"consignment", car.key().as_ref()],
+        bump = consignment.bump
+    )]
+    pub consignment: Option<Account<'info, Consignment>>,
+
+    #[account(mut)]
+    pub dealer: Option<Account<'info, DealerAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    #[account(
+        mut,
+        seeds = [b"installment_plan", car.key().as_ref()],
+        bump = installment_plan.bump
+    )]
+    pub installment_plan: Option<Account<'info, InstallmentPlan>>,
+
+    /// CHECK: The instalment buyer, refunded everything paid into the plan
+    #[account(mut)]
+    pub installment_buyer: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: The owner losing the car, refunded any consignment rent
+    #[account(mut, address = car.owner)]
+    pub previous_owner: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ForceTransfer<'info>>,
+    vin: String,
+    new_owner: Pubkey,
+    reason: ForcedTransferReason,
+    document_uri: String,
+    document_digest: DocumentDigest,
+) -> Result<()> {
+    require!(document_digest.is_valid(), DocumentError::InvalidDocumentDigest);
+    require!(
+        !document_uri.is_empty() && document_uri.len() <= ForcedTransferRecord::MAX_DOCUMENT_URI_LEN,
+        TransferError::InvalidLegalDocument
+    );
+    require!(new_owner != ctx.accounts.car.owner, TransferError::CannotTransferToSelf);
+
+    // Refund every open buy request passed in
+    require!(
+        ctx.remaining_accounts.len().is_multiple_of(2),
+        TransferError::InvalidRefundAccounts
+    );
+    for pair in ctx.remaining_accounts.chunks(2) {
+        let (request_info, buyer_info) = (&pair[0], &pair[1]);
+        require!(request_info.is_writable && buyer_info.is_writable, TransferError::InvalidRefundAccounts);
+
+        let buy_request = Account::<BuyRequest>::try_from(request_info)?;
+        require!(
+            buy_request.vin == vin && buy_request.buyer == buyer_info.key(),
+            TransferError::InvalidRefundAccounts
+        );
+        require!(
+            buy_request.status == BuyRequestStatus::Pending,
+            CarError::InvalidBuyRequestStatus
+        );
+        buy_request.close(buyer_info.clone())?;
+
+        msg!("💸 Buy request from {} refunded", buy_request.buyer);
+    }
+
+    // A forced transfer also ends any consignment
+    if let Some(dealer_key) = ctx.accounts.car.consigned_to {
+        match (&ctx.accounts.consignment, &mut ctx.accounts.dealer) {
+            (Some(consignment), Some(dealer)) => {
+                require_keys_eq!(dealer.key(), dealer_key, DealerError::CarNotConsigned);
+                require_keys_eq!(consignment.dealer, dealer_key, DealerError::CarNotConsigned);
+                consignment.close(ctx.accounts.previous_owner.to_account_info())?;
+                dealer.active_consignments = dealer.active_consignments.saturating_sub(1);
+            }
+            _ => return err!(DealerError::CarConsigned),
+        }
+    }
+
+    // ...and any co-ownership
+    if ctx.accounts.car.is_co_owned {
+        let co_ownership = ctx.accounts.co_ownership
+            .as_ref()
+            .ok_or(CoOwnershipError::CoOwnershipAccountMissing)?;
+        co_ownership.close(ctx.accounts.previous_owner.to_account_info())?;
+    }
+
+    // ...and any instalment plan, whose title can no longer be delivered: the
+    // buyer gets back everything paid in, as when the plan is cancelled
+    if let Some(plan_key) = ctx.accounts.car.installment_plan {
+        match (&ctx.accounts.installment_plan, &ctx.accounts.installment_buyer) {
+            (Some(plan), Some(buyer)) => {
+                require_keys_eq!(plan.key(), plan_key, InstallmentError::InvalidPlanStatus);
+                require_keys_eq!(buyer.key(), plan.buyer, TransferError::InvalidRefundAccounts);
+                let refund = plan.amount_paid;
+                if refund > 0 {
+                    **plan.to_account_info().try_borrow_mut_lamports()? -= refund;
+                    **buyer.to_account_info().try_borrow_mut_lamports()? += refund;
+                }
+                plan.close(ctx.accounts.previous_owner.to_account_info())?;
+
+                msg!("💸 Instalment plan cancelled, {} lamports refunded to {}", refund, plan.buyer);
+            }
+            _ => return err!(InstallmentError::CarUnderInstallmentPlan),
+        }
+    }
+
+    let car = &mut ctx.accounts.car;
+    let government = ctx.accounts.government.key();
+    let now = Clock::get()?.unix_timestamp;
+    let transfer_index = car.next_transfer_index()?;
+
+    ctx.accounts.ownership_record.initialize(
+        car.key(),
+        transfer_index,
+        car.owner,
+        new_owner,
+        None,
+        TransferMechanism::Forced,
+        ctx.bumps.ownership_record,
+    )?;
+
+    let forced_transfer_record = &mut ctx.accounts.forced_transfer_record;
+    forced_transfer_record.car = car.key();
+    forced_transfer_record.transfer_index = transfer_index;
+    forced_transfer_record.reason = reason;
+    forced_transfer_record.document_uri = document_uri;
+    forced_transfer_record.document_digest = document_digest;
+    forced_transfer_record.ordered_by = government;
+    forced_transfer_record.timestamp = now;
+    forced_transfer_record.bump = ctx.bumps.forced_transfer_record;
+
+    // Cancel the listing; bumping the version lets buyers reclaim any
+    // request that was not passed in above
+    car.owner = new_owner;
+    car.is_for_sale = false;
+    car.sale_price = None;
+    car.listing_version = car.listing_version
+        .checked_add(1)
+        .ok_or(CarError::ListingVersionOverflow)?;
+    car.delegate = None;
+    car.consigned_to = None;
+    car.is_co_owned = false;
+    car.installment_plan = None;
+    // Any lien stays with the car: the collateral follows the vehicle
+
+    msg!("⚖️ Car with VIN {} force-transferred to {} by government", vin, new_owner);
+
+    Ok(())
+}
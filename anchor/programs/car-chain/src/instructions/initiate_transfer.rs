@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CoOwnership, DelegateAction, FeeConfig, Organization, PendingTransfer};
+use crate::errors::{CarError, DealerError, InstallmentError, LienError, TransferError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::is_car_action_authorized;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct InitiateTransfer<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = PendingTransfer::LEN,
+        seeds = [b"pending_transfer", car.key().as_ref()],
+        bump
+    )]
+    pub pending_transfer: Account<'info, PendingTransfer>,
+
+    #[account(
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.consigned_to.is_none() @ DealerError::CarConsigned,
+        constraint = car.lienholder.is_none() @ LienError::CarHasLien,
+        constraint = car.installment_plan.is_none() @ InstallmentError::CarUnderInstallmentPlan,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+        constraint = car.is_active @ CarError::CarDeregistered,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    // The current owner, or a delegate allowed to transfer the car
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"fee_config"], bump = fee_config.bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    // Required when the car is co-owned; other co-owners sign as remaining accounts
+    #[account(
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    // Required when the car is owned by an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<InitiateTransfer>,
+    vin: String,
+    recipient: Pubkey,
+    expires_at: i64,
+) -> Result<()> {
+    let car = &ctx.accounts.car;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        is_car_action_authorized(
+            car,
+            ctx.accounts.co_ownership.as_deref(),
+            ctx.accounts.organization.as_ref(),
+            &ctx.accounts.authority.key(),
+            ctx.remaining_accounts,
+            now,
+            DelegateAction::Transfer
+        )?,
+        CarError::UnauthorizedAccess
+    );
+    require!(recipient != car.owner, TransferError::CannotTransferToSelf);
+    require!(expires_at > now, TransferError::InvalidTransferExpiry);
+
+    // The transfer fee is escrowed now and refunded if the transfer never completes
+    let fee_amount = ctx.accounts.fee_config.transfer_fee;
+    if fee_amount > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.pending_transfer.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, fee_amount)?;
+    }
+
+    let pending_transfer = &mut ctx.accounts.pending_transfer;
+    pending_transfer.car = car.key();
+    pending_transfer.vin = vin;
+    pending_transfer.initiator = ctx.accounts.authority.key();
+    pending_transfer.previous_owner = car.owner;
+    pending_transfer.recipient = recipient;
+    pending_transfer.fee_amount = fee_amount;
+    pending_transfer.created_at = now;
+    pending_transfer.expires_at = expires_at;
+    pending_transfer.bump = ctx.bumps.pending_transfer;
+
+    msg!("📨 Transfer of car {} to {} initiated", pending_transfer.vin, recipient);
+
+    Ok(())
+}
@@ -1,31 +1,23 @@
 use anchor_lang::prelude::*;
 use crate::{
-    state::car_report::CarReport,
+    state::car_report::{CarReport, InspectionChecklist, InspectionType, ReportStatus},
     state::car::CarAccount,
+    state::document::DocumentDigest,
+    state::inspection_request::{InspectionRequest, InspectionRequestStatus},
+    state::inspector_stats::InspectorStats,
+    state::inspection_center::InspectionCenter,
     state::user::{UserAccount, VerificationStatus, UserRoles},
-    errors::CarReportError,
+    errors::{CarError, CarReportError, DocumentError, InspectionCenterError, InspectionError},
 };
 use crate::utils::constants::GOVERNMENT_AUTHORITY;
 
 #[derive(Accounts)]
-#[instruction(report_id: u64, vin: String)]
+#[instruction(report_id: u64, vin: String, inspection_type: InspectionType)]
 pub struct IssueCarReport<'info> {
     #[account(
         init,
         payer = inspector_signer,
-        space = 8 + // Discriminator
-            32 + // car: Pubkey
-            32 + // inspector: Pubkey
-            32 + // car_owner: Pubkey
-            8 + // report_date: i64
-            1 + // overall_condition: u8
-            1 + // engine_condition: u8
-            1 + // body_condition: u8
-            256 + // full_report_uri: String (max 256 bytes)
-            512 + // report_summary: String (max 512 bytes)
-            1 + // approved_by_owner: bool
-            512 + // notes: String (max 512 bytes)
-            1, // bump: u8
+        space = 8 + CarReport::MAXIMUM_SIZE,
         seeds = [
             b"car_report",
             car.key().as_ref(),
@@ -39,10 +31,13 @@ pub struct IssueCarReport<'info> {
     #[account(mut,
         seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
         bump = car.bump,
+        constraint = car.is_active @ CarError::CarDeregistered,
     )]
     pub car: Account<'info, CarAccount>,
 
     #[account(
+        seeds = [b"user", inspector_signer.key().as_ref(), inspector.user_name.as_bytes()],
+        bump = inspector.bump,
         constraint = inspector.role == UserRoles::Inspector @ CarReportError::NotAuthorizedInspector,
         constraint = inspector.verification_status == VerificationStatus::Verified @ CarReportError::InspectorNotVerified
     )]
@@ -51,6 +46,39 @@ pub struct IssueCarReport<'info> {
     #[account(mut)]
     pub inspector_signer: Signer<'info>,
 
+    #[account(
+        init_if_needed,
+        payer = inspector_signer,
+        space = InspectorStats::LEN,
+        seeds = [b"inspector_stats", inspector.key().as_ref()],
+        bump,
+        constraint = !inspector_stats.is_suspended() @ InspectionError::InspectorSuspended
+    )]
+    pub inspector_stats: Account<'info, InspectorStats>,
+
+    #[account(
+        constraint = inspection_center.is_active() @ InspectionCenterError::CenterLicenceSuspended,
+        constraint = inspection_center.has_inspector(&inspector.key()) @ InspectionCenterError::InspectorNotMember,
+        constraint = inspection_center.approves(inspection_type) @ InspectionCenterError::InspectionTypeNotApproved
+    )]
+    pub inspection_center: Account<'info, InspectionCenter>,
+
+    // The owner's booking this report answers; its fee goes to the inspector
+    #[account(
+        mut,
+        seeds = [b"inspection_request", car.key().as_ref()],
+        bump = inspection_request.bump,
+        constraint = inspection_request.status == InspectionRequestStatus::Claimed @ InspectionError::NotAssignedInspector,
+        constraint = inspection_request.inspector == Some(inspector_signer.key()) @ InspectionError::NotAssignedInspector,
+        constraint = inspection_request.requested_type == inspection_type @ InspectionError::InspectionTypeMismatch,
+        constraint = inspection_request.owner == car.owner @ InspectionError::StaleInspectionRequest
+    )]
+    pub inspection_request: Account<'info, InspectionRequest>,
+
+    /// CHECK: The owner who booked the inspection, refunded the request rent
+    #[account(mut, address = inspection_request.owner)]
+    pub requester: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -58,20 +86,27 @@ pub fn handler(
     ctx: Context<IssueCarReport>,
     report_id: u64,
     vin: String,
+    inspection_type: InspectionType,
+    passed: bool,
     overall_condition: u8,
     engine_condition: u8,
     body_condition: u8,
+    checklist: InspectionChecklist,
     full_report_uri: String,
+    full_report_digest: DocumentDigest,
     report_summary: String,
     notes: String,
 ) -> Result<()> {
     // التحقق من صحة البيانات
-    require!(overall_condition >= 1 && overall_condition <= 10, CarReportError::InvalidConditionScore);
-    require!(engine_condition >= 1 && engine_condition <= 10, CarReportError::InvalidConditionScore);
-    require!(body_condition >= 1 && body_condition <= 10, CarReportError::InvalidConditionScore);
+    require!((1..=10).contains(&overall_condition), CarReportError::InvalidConditionScore);
+    require!((1..=10).contains(&engine_condition), CarReportError::InvalidConditionScore);
+    require!((1..=10).contains(&body_condition), CarReportError::InvalidConditionScore);
     require!(notes.len() <= CarReport::MAX_NOTES_LENGTH, CarReportError::NotesTooLong);
-    require!(full_report_uri.len() <= 256, CarReportError::UriTooLong);
-    require!(report_summary.len() <= 512, CarReportError::SummaryTooLong);
+    require!(full_report_uri.len() <= CarReport::MAX_URI_LENGTH, CarReportError::UriTooLong);
+    require!(report_summary.len() <= CarReport::MAX_SUMMARY_LENGTH, CarReportError::SummaryTooLong);
+    require!(full_report_digest.is_valid(), DocumentError::InvalidDocumentDigest);
+    require!(checklist.is_valid(), CarReportError::InvalidChecklist);
+    require!(!(passed && checklist.has_failures()), CarReportError::ChecklistContradictsVerdict);
 
     let car = &ctx.accounts.car;
     let inspector = &ctx.accounts.inspector;
@@ -85,17 +120,39 @@ pub fn handler(
     report.report_id = report_id;
     report.car = car.key();
     report.inspector = inspector.key();
+    report.inspection_center = ctx.accounts.inspection_center.key();
     report.car_owner = car.owner;
     report.report_date = Clock::get()?.unix_timestamp;
+    report.inspection_type = inspection_type;
+    report.passed = passed;
     report.overall_condition = overall_condition;
     report.engine_condition = engine_condition;
     report.body_condition = body_condition;
+    report.checklist = checklist;
     report.full_report_uri = full_report_uri;
+    report.full_report_digest = full_report_digest;
     report.report_summary = report_summary;
-    report.approved_by_owner = false; // يحتاج موافقة المالك
+    report.status = ReportStatus::Pending; // يحتاج موافقة المالك
+    report.revision = 0;
+    report.previous_revision = None;
+    report.superseded_by = None;
     report.notes = notes;
     report.bump = ctx.bumps.car_report;
 
+    let stats = &mut ctx.accounts.inspector_stats;
+    stats.inspector = inspector.key();
+    stats.reports_issued = stats.reports_issued.saturating_add(1);
+    stats.last_activity_at = report.report_date;
+    stats.bump = ctx.bumps.inspector_stats;
+
+    // Release the escrowed fee to the inspector and close the booking
+    let fee = ctx.accounts.inspection_request.fee;
+    if fee > 0 {
+        **ctx.accounts.inspection_request.to_account_info().try_borrow_mut_lamports()? -= fee;
+        **ctx.accounts.inspector_signer.to_account_info().try_borrow_mut_lamports()? += fee;
+    }
+    ctx.accounts.inspection_request.close(ctx.accounts.requester.to_account_info())?;
+
     msg!("New car inspection report created for car: {}", car.key());
 
     Ok(())
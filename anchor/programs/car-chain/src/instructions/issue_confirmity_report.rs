@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
 use crate::{
     state::confirmity_report::ConformityReport,
+    state::car_report::ReportStatus,
     state::car::CarAccount,
+    state::document::DocumentDigest,
     state::user::{UserAccount, VerificationStatus, UserRoles},
-    errors::ConfirmityReportError,
+    errors::{CarError, ConfirmityReportError, DocumentError},
 };
 use crate::utils::constants::GOVERNMENT_AUTHORITY;
 
@@ -13,17 +15,7 @@ pub struct IssueConformityReport<'info> {
     #[account(
         init,
         payer = confirmity_expert_signer,
-        space = 8 + // Discriminator
-            32 + // car: Pubkey
-            32 + // confirmity_expert: Pubkey
-            32 + // car_owner: Pubkey
-            8 + // inspection_date: i64
-            1 + // conformity_status: bool
-            256 + // modifications: String (max 256 bytes)
-            256 + // confirmity_expert_signature: String (max 256 bytes)
-            256 + // mines_stamp: String (max 256 bytes)
-            512 + // notes: String (max 512 bytes)
-            1, // bump: u8
+        space = ConformityReport::MAXIMUM_SIZE,
         seeds = [
             b"conformity_report",
             car.key().as_ref(),
@@ -37,6 +29,7 @@ pub struct IssueConformityReport<'info> {
     #[account(mut,
         seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
         bump = car.bump,
+        constraint = car.is_active @ CarError::CarDeregistered,
     )]
     pub car: Account<'info, CarAccount>,
 
@@ -59,9 +52,11 @@ pub fn handler(
     conformity_status: bool,
     modifications: String,
     full_report_uri: String,
+    full_report_digest: DocumentDigest,
     mines_stamp: String,
     notes: String,
 ) -> Result<()> {
+    require!(full_report_digest.is_valid(), DocumentError::InvalidDocumentDigest);
     require!(modifications.len() <= 256, ConfirmityReportError::ModificationsTooLong);
     require!(mines_stamp.len() <= 256, ConfirmityReportError::StampTooLong);
     require!(notes.len() <= ConformityReport::MAX_NOTES_LENGTH, ConfirmityReportError::NotesTooLong);
@@ -81,8 +76,12 @@ pub fn handler(
     report.conformity_status = conformity_status;
     report.modifications = modifications;
     report.full_report_uri = full_report_uri;
+    report.full_report_digest = full_report_digest;
     report.mines_stamp = mines_stamp;
-    report.accepted_by_owner = false;
+    report.status = ReportStatus::Pending;
+    report.revision = 0;
+    report.previous_revision = None;
+    report.superseded_by = None;
     report.notes = notes;
     report.bump = ctx.bumps.conformity_report;
 
@@ -11,6 +11,60 @@ pub mod issue_car_report;
 pub mod accept_report;
 pub mod issue_confirmity_report;
 pub mod accept_confirmity_report;
+pub mod update_listing_price;
+pub mod reclaim_buy_request;
+pub mod set_fee_config;
+pub mod withdraw_treasury;
+pub mod set_tax_config;
+pub mod withdraw_tax_treasury;
+pub mod register_dealer;
+pub mod update_dealer_staff;
+pub mod consign_car;
+pub mod end_consignment;
+pub mod batch_set_for_sale;
+pub mod batch_cancel_for_sale;
+pub mod approve_delegate;
+pub mod revoke_delegate;
+pub mod initiate_transfer;
+pub mod accept_transfer;
+pub mod decline_transfer;
+pub mod cancel_transfer;
+pub mod force_transfer;
+pub mod enable_co_ownership;
+pub mod dissolve_co_ownership;
+pub mod register_organization;
+pub mod verify_organization;
+pub mod update_organization_member;
+pub mod withdraw_organization_funds;
+pub mod register_lien;
+pub mod update_lien_payoff;
+pub mod release_lien;
+pub mod propose_installment_plan;
+pub mod accept_installment_plan;
+pub mod pay_installment;
+pub mod complete_installment_plan;
+pub mod claim_installment_default;
+pub mod cancel_installment_plan;
+pub mod report_stolen;
+pub mod recover_vehicle;
+pub mod deregister_car;
+pub mod reregister_car;
+pub mod record_accident;
+pub mod update_accident_repair;
+pub mod set_inspection_config;
+pub mod refresh_inspection_status;
+pub mod request_inspection;
+pub mod claim_inspection;
+pub mod cancel_inspection_request;
+pub mod dispute_report;
+pub mod resolve_dispute;
+pub mod amend_report;
+pub mod amend_confirmity_report;
+pub mod revoke_report;
+pub mod reinstate_inspector;
+pub mod register_inspection_center;
+pub mod update_inspection_center;
+pub mod update_center_inspectors;
 
 
 
@@ -26,4 +80,58 @@ pub use transfer_car::*;
 pub use issue_car_report::*;
 pub use accept_report::*;
 pub use issue_confirmity_report::*;
-pub use accept_confirmity_report::*;
\ No newline at end of file
+pub use accept_confirmity_report::*;
+pub use update_listing_price::*;
+pub use reclaim_buy_request::*;
+pub use set_fee_config::*;
+pub use withdraw_treasury::*;
+pub use set_tax_config::*;
+pub use withdraw_tax_treasury::*;
+pub use register_dealer::*;
+pub use update_dealer_staff::*;
+pub use consign_car::*;
+pub use end_consignment::*;
+pub use batch_set_for_sale::*;
+pub use batch_cancel_for_sale::*;
+pub use approve_delegate::*;
+pub use revoke_delegate::*;
+pub use initiate_transfer::*;
+pub use accept_transfer::*;
+pub use decline_transfer::*;
+pub use cancel_transfer::*;
+pub use force_transfer::*;
+pub use enable_co_ownership::*;
+pub use dissolve_co_ownership::*;
+pub use register_organization::*;
+pub use verify_organization::*;
+pub use update_organization_member::*;
+pub use withdraw_organization_funds::*;
+pub use register_lien::*;
+pub use update_lien_payoff::*;
+pub use release_lien::*;
+pub use propose_installment_plan::*;
+pub use accept_installment_plan::*;
+pub use pay_installment::*;
+pub use complete_installment_plan::*;
+pub use claim_installment_default::*;
+pub use cancel_installment_plan::*;
+pub use report_stolen::*;
+pub use recover_vehicle::*;
+pub use deregister_car::*;
+pub use reregister_car::*;
+pub use record_accident::*;
+pub use update_accident_repair::*;
+pub use set_inspection_config::*;
+pub use refresh_inspection_status::*;
+pub use request_inspection::*;
+pub use claim_inspection::*;
+pub use cancel_inspection_request::*;
+pub use dispute_report::*;
+pub use resolve_dispute::*;
+pub use amend_report::*;
+pub use amend_confirmity_report::*;
+pub use revoke_report::*;
+pub use reinstate_inspector::*;
+pub use register_inspection_center::*;
+pub use update_inspection_center::*;
+pub use update_center_inspectors::*;
\ No newline at end of file
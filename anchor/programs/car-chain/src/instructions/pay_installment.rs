@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, InstallmentPlan, InstallmentStatus};
+use crate::errors::{CarError, InstallmentError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct PayInstallment<'info> {
+    #[account(
+        mut,
+        seeds = [b"installment_plan", car.key().as_ref()],
+        bump = installment_plan.bump,
+        has_one = buyer,
+        constraint = installment_plan.status == InstallmentStatus::Active @ InstallmentError::InvalidPlanStatus,
+        constraint = !installment_plan.is_fully_paid() @ InstallmentError::PlanFullyPaid
+    )]
+    pub installment_plan: Account<'info, InstallmentPlan>,
+
+    #[account(
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.installment_plan == Some(installment_plan.key()) @ InstallmentError::InvalidPlanStatus,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<PayInstallment>, _vin: String) -> Result<()> {
+    // Late payments are accepted until the seller claims the default
+    let installment_amount = ctx.accounts.installment_plan.installment_amount;
+    let cpi_context = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        anchor_lang::system_program::Transfer {
+            from: ctx.accounts.buyer.to_account_info(),
+            to: ctx.accounts.installment_plan.to_account_info(),
+        },
+    );
+    anchor_lang::system_program::transfer(cpi_context, installment_amount)?;
+
+    let plan = &mut ctx.accounts.installment_plan;
+    plan.amount_paid = plan.amount_paid
+        .checked_add(installment_amount)
+        .ok_or(InstallmentError::AmountOverflow)?;
+    plan.installments_paid += 1;
+    plan.next_due_at = plan.next_due_at
+        .checked_add(plan.interval_seconds)
+        .ok_or(InstallmentError::AmountOverflow)?;
+
+    msg!(
+        "📆 Instalment {}/{} paid for car {}",
+        plan.installments_paid,
+        plan.installment_count,
+        plan.vin
+    );
+
+    Ok(())
+}
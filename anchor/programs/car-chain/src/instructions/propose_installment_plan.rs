@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, DelegateAction, InstallmentPlan, InstallmentStatus, InstallmentTerms};
+use crate::errors::{CarError, CoOwnershipError, DealerError, InstallmentError, LienError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct ProposeInstallmentPlan<'info> {
+    #[account(
+        init,
+        payer = seller,
+        space = InstallmentPlan::LEN,
+        seeds = [b"installment_plan", car.key().as_ref()],
+        bump
+    )]
+    pub installment_plan: Account<'info, InstallmentPlan>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.installment_plan.is_none() @ InstallmentError::CarUnderInstallmentPlan,
+        constraint = car.consigned_to.is_none() @ DealerError::CarConsigned,
+        constraint = car.lienholder.is_none() @ LienError::CarHasLien,
+        constraint = !car.is_co_owned @ CoOwnershipError::CarCoOwned,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+        constraint = car.is_active @ CarError::CarDeregistered,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ProposeInstallmentPlan>,
+    vin: String,
+    buyer: Pubkey,
+    terms: InstallmentTerms,
+) -> Result<()> {
+    let InstallmentTerms {
+        down_payment,
+        installment_amount,
+        installment_count,
+        interval_seconds,
+        grace_period_seconds,
+        default_refund_bps,
+    } = terms;
+    let car = &mut ctx.accounts.car;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Only the owner can commit the car to a plan; the proceeds go to them
+    require!(
+        car.is_authorized(&ctx.accounts.seller.key(), now, DelegateAction::AcceptOffers)
+            && car.owner == ctx.accounts.seller.key(),
+        CarError::UnauthorizedAccess
+    );
+    require!(buyer != car.owner, CarError::CannotBuyOwnCar);
+    require!(installment_amount > 0, InstallmentError::InvalidPlanAmounts);
+    require!(
+        installment_count > 0 && interval_seconds > 0 && grace_period_seconds >= 0,
+        InstallmentError::InvalidSchedule
+    );
+    require!(default_refund_bps <= InstallmentPlan::MAX_BPS, InstallmentError::InvalidRefundRate);
+
+    let total_price = installment_amount
+        .checked_mul(installment_count as u64)
+        .and_then(|instalments| instalments.checked_add(down_payment))
+        .ok_or(InstallmentError::AmountOverflow)?;
+
+    let plan = &mut ctx.accounts.installment_plan;
+    plan.car = car.key();
+    plan.vin = vin;
+    plan.seller = car.owner;
+    plan.buyer = buyer;
+    plan.total_price = total_price;
+    plan.down_payment = down_payment;
+    plan.installment_amount = installment_amount;
+    plan.installment_count = installment_count;
+    plan.installments_paid = 0;
+    plan.interval_seconds = interval_seconds;
+    plan.grace_period_seconds = grace_period_seconds;
+    plan.next_due_at = 0;
+    plan.amount_paid = 0;
+    plan.default_refund_bps = default_refund_bps;
+    plan.status = InstallmentStatus::Proposed;
+    plan.created_at = now;
+    plan.bump = ctx.bumps.installment_plan;
+
+    // The car is off the market while the plan runs
+    car.installment_plan = Some(plan.key());
+    car.is_for_sale = false;
+    car.sale_price = None;
+
+    msg!(
+        "📆 Instalment plan proposed for car {}: {} down + {} x {} lamports",
+        plan.vin,
+        down_payment,
+        installment_count,
+        installment_amount
+    );
+
+    Ok(())
+}
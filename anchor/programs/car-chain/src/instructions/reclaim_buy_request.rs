@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{BuyRequest, BuyRequestStatus, CarAccount, Organization};
+use crate::errors::CarError;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::acquiring_owner;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct ReclaimBuyRequest<'info> {
+    #[account(
+        mut,
+        seeds = [b"buy_request", vin.as_bytes(), buy_request.buyer.as_ref()],
+        bump,
+        constraint = buy_request.status == BuyRequestStatus::Pending @ CarError::InvalidBuyRequestStatus,
+    )]
+    pub buy_request: Account<'info, BuyRequest>,
+
+    // A request is refundable once the listing it was made against is gone:
+    // the price changed, the car was delisted, or the seller no longer owns it.
+    #[account(
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = !car.is_for_sale
+            || car.listing_version != buy_request.listing_version
+            || car.owner != buy_request.seller @ CarError::BuyRequestStillActive,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    // The buyer, or an admin of the buying organization
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    // Required when the request was made for an organization; it gets the refund
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<ReclaimBuyRequest>, vin: String) -> Result<()> {
+    let buyer = acquiring_owner(&ctx.accounts.buyer.key(), ctx.accounts.organization.as_ref())?;
+    require!(ctx.accounts.buy_request.buyer == buyer, CarError::UnauthorizedAccess);
+
+    // Closing the request returns the escrowed amount plus rent to the buyer
+    let refund_to = match &ctx.accounts.organization {
+        Some(organization) => organization.to_account_info(),
+        None => ctx.accounts.buyer.to_account_info(),
+    };
+    ctx.accounts.buy_request.close(refund_to)?;
+
+    msg!(
+        "💸 Buy request for car {} reclaimed, {} lamports refunded",
+        vin,
+        ctx.accounts.buy_request.amount
+    );
+
+    Ok(())
+}
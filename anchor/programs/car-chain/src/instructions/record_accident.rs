@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::{AccidentDetails, AccidentRecord, CarAccount, DocumentDigest, UserAccount, VerificationStatus};
+use crate::errors::{AccidentError, CarError, DocumentError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct RecordAccident<'info> {
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.is_active @ CarError::CarDeregistered,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = AccidentRecord::LEN,
+        seeds = [b"accident", car.key().as_ref(), &car.accident_count.to_le_bytes()],
+        bump
+    )]
+    pub accident_record: Account<'info, AccidentRecord>,
+
+    #[account(
+        seeds = [b"user", reporter.key().as_ref(), reporter_user.user_name.as_bytes()],
+        bump = reporter_user.bump,
+        constraint = AccidentRecord::can_report(&reporter_user.role) @ AccidentError::NotAuthorizedReporter,
+        constraint = reporter_user.verification_status == VerificationStatus::Verified @ AccidentError::ReporterNotVerified
+    )]
+    pub reporter_user: Account<'info, UserAccount>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RecordAccident>,
+    vin: String,
+    details: AccidentDetails,
+    document_uri: String,
+    document_digest: DocumentDigest,
+) -> Result<()> {
+    let AccidentDetails { accident_date, severity, damaged_zones, repair_status } = details;
+    require!(document_digest.is_valid(), DocumentError::InvalidDocumentDigest);
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(accident_date <= now, AccidentError::InvalidAccidentDate);
+    require!(damaged_zones.any(), AccidentError::NoDamagedZones);
+    require!(
+        !document_uri.is_empty() && document_uri.len() <= AccidentRecord::MAX_DOCUMENT_URI_LEN,
+        AccidentError::InvalidDocumentUri
+    );
+
+    let car = &mut ctx.accounts.car;
+    let record = &mut ctx.accounts.accident_record;
+
+    record.car = car.key();
+    record.accident_index = car.accident_count;
+    record.reported_by = ctx.accounts.reporter.key();
+    record.reporter_role = ctx.accounts.reporter_user.role.clone();
+    record.accident_date = accident_date;
+    record.severity = severity;
+    record.damaged_zones = damaged_zones;
+    record.repair_status = repair_status;
+    record.document_uri = document_uri;
+    record.document_digest = document_digest;
+    record.created_at = now;
+    record.updated_at = now;
+    record.bump = ctx.bumps.accident_record;
+
+    // Buyers acknowledge the accident count in request_buy, so a new record
+    // invalidates what they saw before
+    car.accident_count = car.accident_count
+        .checked_add(1)
+        .ok_or(AccidentError::AccidentCountOverflow)?;
+    if severity.is_severe() {
+        car.has_severe_damage = true;
+    }
+
+    msg!(
+        "💥 Accident #{} recorded for car {} by {}",
+        record.accident_index,
+        vin,
+        record.reported_by
+    );
+
+    Ok(())
+}
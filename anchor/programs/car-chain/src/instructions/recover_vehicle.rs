@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CoOwnership, InstallmentPlan, InstallmentStatus, Organization, UserAccount};
+use crate::errors::{CarError, InstallmentError};
+use crate::events::VehicleRecovered;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::{has_stake_in_car, is_law_enforcement};
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct RecoverVehicle<'info> {
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.is_stolen @ CarError::CarNotStolen,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    pub authority: Signer<'info>,
+
+    // Required when recovering as law enforcement
+    #[account(
+        seeds = [b"user", authority.key().as_ref(), authority_user.user_name.as_bytes()],
+        bump = authority_user.bump
+    )]
+    pub authority_user: Option<Account<'info, UserAccount>>,
+
+    #[account(
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+
+    // Required when the car is under an instalment plan
+    #[account(
+        mut,
+        seeds = [b"installment_plan", car.key().as_ref()],
+        bump = installment_plan.bump
+    )]
+    pub installment_plan: Option<Account<'info, InstallmentPlan>>,
+}
+
+pub fn handler(ctx: Context<RecoverVehicle>, vin: String) -> Result<()> {
+    let car = &mut ctx.accounts.car;
+    let authority = ctx.accounts.authority.key();
+
+    // Police can always close a theft report. The owner's side can only
+    // withdraw a report it filed itself, not one raised by the police.
+    let is_reporting_party = car.stolen_reported_by == Some(authority)
+        && has_stake_in_car(
+            car,
+            ctx.accounts.co_ownership.as_deref(),
+            ctx.accounts.organization.as_ref(),
+            &authority,
+        );
+    require!(
+        is_law_enforcement(ctx.accounts.authority_user.as_deref(), &authority) || is_reporting_party,
+        CarError::UnauthorizedTheftReport
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    // The buyer could not pay while the car was flagged, so the schedule
+    // moves back by the time it spent reported stolen
+    if let Some(plan_key) = car.installment_plan {
+        let plan = ctx.accounts.installment_plan
+            .as_mut()
+            .ok_or(InstallmentError::CarUnderInstallmentPlan)?;
+        require_keys_eq!(plan.key(), plan_key, InstallmentError::InvalidPlanStatus);
+        if plan.status == InstallmentStatus::Active {
+            let stolen_for = now.saturating_sub(car.stolen_reported_at.unwrap_or(now));
+            plan.next_due_at = plan.next_due_at.saturating_add(stolen_for);
+        }
+    }
+
+    car.is_stolen = false;
+    car.stolen_reported_by = None;
+    car.stolen_reported_at = None;
+
+    emit!(VehicleRecovered {
+        car: car.key(),
+        vin: vin.clone(),
+        recovered_by: authority,
+        timestamp: now,
+    });
+
+    msg!("✅ Car {} recovered", vin);
+
+    Ok(())
+}
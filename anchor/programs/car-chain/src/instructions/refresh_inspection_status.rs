@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, InspectionStatus};
+use crate::errors::InspectionError;
+
+// Permissionless crank: cars to check are passed as writable remaining
+// accounts and those whose inspection has lapsed are marked Expired.
+#[derive(Accounts)]
+pub struct RefreshInspectionStatus<'info> {
+    pub cranker: Signer<'info>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RefreshInspectionStatus<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let mut expired = 0u32;
+
+    for car_info in ctx.remaining_accounts.iter() {
+        require!(car_info.is_writable, InspectionError::CarNotWritable);
+
+        let mut car = Account::<CarAccount>::try_from(car_info)?;
+        if car.inspection_status == InspectionStatus::Expired || !car.is_inspection_expired(now) {
+            continue;
+        }
+
+        car.inspection_status = InspectionStatus::Expired;
+        car.exit(&crate::ID)?;
+        expired += 1;
+
+        msg!("⏰ Inspection for car {} has expired", car.vin);
+    }
+
+    msg!("{} of {} cars marked as expired", expired, ctx.remaining_accounts.len());
+
+    Ok(())
+}
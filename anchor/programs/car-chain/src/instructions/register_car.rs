@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::state::{CarAccount,InspectionStatus };
+use crate::state::{CarAccount, FeeConfig, FeeKind, InspectionStatus, Treasury, VehicleCategory};
 use crate::errors::CarError;
 use crate::errors::CustomError;
+use crate::events::FeeCollected;
 use crate::utils::constants::GOVERNMENT_AUTHORITY;
 
 #[derive(Accounts)]
@@ -25,6 +26,16 @@ pub struct RegisterCar<'info> {
         constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
     )]
     pub government: Signer<'info>,
+
+    // Pays the registration fee: the owner, or a dealer registering on their behalf
+    #[account(mut)]
+    pub registrant: Signer<'info>,
+
+    #[account(seeds = [b"fee_config"], bump = fee_config.bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
     
     pub system_program: Program<'info, System>,
 }
@@ -43,6 +54,7 @@ pub fn handler(
     inspection_status: InspectionStatus,
     latest_inspection_report: Option<String>,
     mileage: u32,
+    category: VehicleCategory,
     bump: u8,
 ) -> Result<()> {
 
@@ -71,23 +83,50 @@ pub fn handler(
     car.transfer_count = 0;
     car.last_inspection_date = last_inspection_date;
     car.inspection_status=inspection_status;
+    car.inspection_expires_at = None;
     car.latest_inspection_report=latest_inspection_report;
     car.mileage = mileage;
     car.is_for_sale = false;
     car.sale_price = None;
+    car.listing_version = 0;
+    car.consigned_to = None;
+    car.delegate = None;
+    car.is_co_owned = false;
+    car.lienholder = None;
+    car.installment_plan = None;
+    car.is_stolen = false;
+    car.stolen_reported_by = None;
+    car.stolen_reported_at = None;
+    car.registration_count = 1;
+    car.last_deregistration = None;
+    car.accident_count = 0;
+    car.has_severe_damage = false;
+    car.category = category;
     car.bump = ctx.bumps.car;
 
+    // رسوم التسجيل
+    let registration_fee = ctx.accounts.fee_config.registration_fee;
+    if registration_fee > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.registrant.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, registration_fee)?;
+        ctx.accounts.treasury.record_fee(registration_fee)?;
+
+        emit!(FeeCollected {
+            kind: FeeKind::Registration,
+            vin: car.vin.clone(),
+            payer: ctx.accounts.registrant.key(),
+            amount: registration_fee,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     msg!("سيارة جديدة تم إنشاؤها: ID {}, VIN: {}, المالك: {}", car.car_id, car.vin, owner);
 
     Ok(())
 }
-
-
-
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
-pub enum InspectionType {
-    Periodic,     // فحص دوري
-    PreSale,      // فحص ما قبل البيع
-    PostAccident, // فحص ما بعد الحادث
-    Custom,       // فحص مخصص
-}
\ No newline at end of file
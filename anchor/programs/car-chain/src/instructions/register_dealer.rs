@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::{DealerAccount, UserAccount, UserRoles, VerificationStatus};
+use crate::errors::DealerError;
+
+#[derive(Accounts)]
+pub struct RegisterDealer<'info> {
+    #[account(
+        init,
+        payer = dealer_signer,
+        space = DealerAccount::LEN,
+        seeds = [b"dealer", dealer_signer.key().as_ref()],
+        bump
+    )]
+    pub dealer: Account<'info, DealerAccount>,
+
+    #[account(
+        seeds = [b"user", dealer_signer.key().as_ref(), dealer_user.user_name.as_bytes()],
+        bump = dealer_user.bump,
+        constraint = dealer_user.role == UserRoles::Dealer @ DealerError::NotDealer,
+        constraint = dealer_user.verification_status == VerificationStatus::Verified @ DealerError::DealerNotVerified
+    )]
+    pub dealer_user: Account<'info, UserAccount>,
+
+    #[account(mut)]
+    pub dealer_signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterDealer>, licence_number: String) -> Result<()> {
+    require!(
+        !licence_number.is_empty() && licence_number.len() <= DealerAccount::MAX_LICENCE_LEN,
+        DealerError::InvalidLicence
+    );
+
+    let dealer = &mut ctx.accounts.dealer;
+    dealer.authority = ctx.accounts.dealer_signer.key();
+    dealer.user_account = ctx.accounts.dealer_user.key();
+    dealer.licence_number = licence_number;
+    dealer.staff = Vec::new();
+    dealer.active_consignments = 0;
+    dealer.created_at = Clock::get()?.unix_timestamp;
+    dealer.bump = ctx.bumps.dealer;
+
+    msg!("🏪 Dealer registered with licence {}", dealer.licence_number);
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{CenterLicenceStatus, DocumentDigest, InspectionCenter, InspectionType};
+use crate::errors::{CustomError, DocumentError, InspectionCenterError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(licence_number: String)]
+pub struct RegisterInspectionCenter<'info> {
+    #[account(
+        init,
+        payer = government,
+        space = InspectionCenter::LEN,
+        seeds = [b"inspection_center", licence_number.as_bytes()],
+        bump
+    )]
+    pub inspection_center: Account<'info, InspectionCenter>,
+
+    /// CHECK: The wallet operating the centre
+    pub operator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterInspectionCenter>,
+    licence_number: String,
+    name: String,
+    address_uri: String,
+    address_digest: DocumentDigest,
+    capacity: u16,
+    approved_inspection_types: Vec<InspectionType>,
+) -> Result<()> {
+    require!(
+        !licence_number.is_empty() && licence_number.len() <= InspectionCenter::MAX_LICENCE_LEN,
+        InspectionCenterError::InvalidLicence
+    );
+    require!(
+        !name.is_empty() && name.len() <= InspectionCenter::MAX_NAME_LEN,
+        InspectionCenterError::InvalidName
+    );
+    require!(
+        !address_uri.is_empty() && address_uri.len() <= InspectionCenter::MAX_ADDRESS_URI_LEN,
+        InspectionCenterError::InvalidAddressUri
+    );
+    require!(address_digest.is_valid(), DocumentError::InvalidDocumentDigest);
+    require!(capacity > 0, InspectionCenterError::InvalidCapacity);
+    require!(
+        InspectionCenter::valid_inspection_types(&approved_inspection_types),
+        InspectionCenterError::InvalidInspectionTypes
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let center = &mut ctx.accounts.inspection_center;
+    center.authority = ctx.accounts.operator.key();
+    center.licence_number = licence_number;
+    center.name = name;
+    center.address_uri = address_uri;
+    center.address_digest = address_digest;
+    center.capacity = capacity;
+    center.approved_inspection_types = approved_inspection_types;
+    center.licence_status = CenterLicenceStatus::Active;
+    center.inspectors = Vec::new();
+    center.registered_at = now;
+    center.updated_at = now;
+    center.bump = ctx.bumps.inspection_center;
+
+    msg!("🏭 Inspection centre registered with licence {}", center.licence_number);
+
+    Ok(())
+}
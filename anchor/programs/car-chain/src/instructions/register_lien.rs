@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, Lien, UserAccount, UserRoles, VerificationStatus};
+use crate::errors::{CoOwnershipError, CustomError, InstallmentError, LienError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+// Both the lender and the owner sign: the owner's signature is the consent
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct RegisterLien<'info> {
+    #[account(
+        init,
+        payer = lender,
+        space = Lien::LEN,
+        seeds = [b"lien", car.key().as_ref()],
+        bump
+    )]
+    pub lien: Account<'info, Lien>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.owner == owner.key() @ CustomError::NotCarOwner,
+        constraint = !car.is_co_owned @ CoOwnershipError::CarCoOwned,
+        constraint = car.lienholder.is_none() @ LienError::LienAlreadyActive,
+        constraint = car.installment_plan.is_none() @ InstallmentError::CarUnderInstallmentPlan,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(
+        seeds = [b"user", lender.key().as_ref(), lender_user.user_name.as_bytes()],
+        bump = lender_user.bump,
+        constraint = lender_user.role == UserRoles::Lender @ LienError::NotLender,
+        constraint = lender_user.verification_status == VerificationStatus::Verified @ LienError::LenderNotVerified
+    )]
+    pub lender_user: Account<'info, UserAccount>,
+
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterLien>,
+    vin: String,
+    loan_reference: String,
+    principal: u64,
+    outstanding: u64,
+) -> Result<()> {
+    require!(
+        loan_reference.len() <= Lien::MAX_LOAN_REFERENCE_LEN,
+        LienError::LoanReferenceTooLong
+    );
+    require!(
+        outstanding > 0 && outstanding <= principal,
+        LienError::InvalidLienAmount
+    );
+
+    let car = &mut ctx.accounts.car;
+    let lien = &mut ctx.accounts.lien;
+    let now = Clock::get()?.unix_timestamp;
+
+    lien.car = car.key();
+    lien.lender = ctx.accounts.lender.key();
+    lien.owner_at_registration = car.owner;
+    lien.loan_reference = loan_reference;
+    lien.principal = principal;
+    lien.outstanding = outstanding;
+    lien.registered_at = now;
+    lien.updated_at = now;
+    lien.bump = ctx.bumps.lien;
+
+    car.lienholder = Some(lien.lender);
+
+    msg!("🏦 Lien registered on car {} for {} lamports", vin, outstanding);
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::state::{Organization, OrganizationMember, OrganizationRole, VerificationStatus};
+use crate::errors::OrganizationError;
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct RegisterOrganization<'info> {
+    #[account(
+        init,
+        payer = admin,
+        space = Organization::LEN,
+        seeds = [b"organization", name.as_bytes()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RegisterOrganization>,
+    name: String,
+    registration_number: String,
+) -> Result<()> {
+    require!(
+        !name.is_empty() && name.len() <= Organization::MAX_NAME_LEN,
+        OrganizationError::InvalidName
+    );
+    require!(
+        !registration_number.is_empty()
+            && registration_number.len() <= Organization::MAX_REGISTRATION_NUMBER_LEN,
+        OrganizationError::InvalidRegistrationNumber
+    );
+
+    let organization = &mut ctx.accounts.organization;
+    let clock = Clock::get()?;
+
+    organization.name = name;
+    organization.registration_number = registration_number;
+    organization.members = vec![OrganizationMember {
+        key: ctx.accounts.admin.key(),
+        role: OrganizationRole::Admin,
+    }];
+    organization.verification_status = VerificationStatus::Pending;
+    organization.verified_at = None;
+    organization.created_by = ctx.accounts.admin.key();
+    organization.created_at = clock.unix_timestamp;
+    organization.bump = ctx.bumps.organization;
+
+    msg!("🏢 Organization registered: {}", organization.name);
+
+    Ok(())
+}
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::state::{UserAccount, UserRoles, VerificationStatus};
-use crate::errors::ErrorCode;
+use crate::state::{DocumentDigest, UserAccount, UserRoles, VerificationStatus};
+use crate::errors::{DocumentError, ErrorCode};
 
 use crate::utils::constants::GOVERNMENT_AUTHORITY;
 
 #[derive(Accounts)]
-#[instruction(user_name: String, public_data_uri: String, private_data_uri: String, encrypted_key_for_gov: String, encrypted_key_for_user: String, role: UserRoles)]
+#[instruction(user_name: String, public_data_uri: String, public_data_digest: DocumentDigest, private_data_uri: String, private_data_digest: DocumentDigest, encrypted_key_for_gov: String, encrypted_key_for_user: String, role: UserRoles)]
 pub struct RegisterUser<'info> {
     #[account(
         init,
@@ -30,11 +30,15 @@ pub fn handler(
     ctx: Context<RegisterUser>,
     user_name: String,
     public_data_uri: String,
+    public_data_digest: DocumentDigest,
     private_data_uri: String,
+    private_data_digest: DocumentDigest,
     encrypted_key_for_gov: String,
     encrypted_key_for_user: String,
     role: UserRoles
 ) -> Result<()> {
+    require!(public_data_digest.is_valid(), DocumentError::InvalidDocumentDigest);
+    require!(private_data_digest.is_valid(), DocumentError::InvalidDocumentDigest);
     let user_account = &mut ctx.accounts.user_account;
     let clock = Clock::get()?;
     
@@ -47,7 +51,9 @@ pub fn handler(
     user_account.authority = ctx.accounts.user_signer.key();
     user_account.user_name = user_name;
     user_account.public_data_uri = public_data_uri;
+    user_account.public_data_digest = public_data_digest;
     user_account.private_data_uri = private_data_uri;
+    user_account.private_data_digest = private_data_digest;
     user_account.encrypted_key_for_gov = encrypted_key_for_gov;
     user_account.encrypted_key_for_user = encrypted_key_for_user;
     user_account.role = role;
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::InspectorStats;
+use crate::errors::{CustomError, InspectionError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+// Lifts an automatic suspension. The voided count is kept, so the next
+// voided report suspends the inspector again.
+#[derive(Accounts)]
+pub struct ReinstateInspector<'info> {
+    #[account(
+        mut,
+        seeds = [b"inspector_stats", inspector_stats.inspector.as_ref()],
+        bump = inspector_stats.bump,
+        constraint = inspector_stats.is_suspended() @ InspectionError::InspectorNotSuspended
+    )]
+    pub inspector_stats: Account<'info, InspectorStats>,
+
+    #[account(
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReinstateInspector>) -> Result<()> {
+    let stats = &mut ctx.accounts.inspector_stats;
+    stats.suspended_at = None;
+
+    msg!("🏛️ Inspector {} reinstated", stats.inspector);
+
+    Ok(())
+}
@@ -1,20 +1,38 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::is_car_action_authorized;
 
 #[derive(Accounts)]
 #[instruction(vin: String)]
 pub struct RejectBuyRequest<'info> {
+    // The owner of the car, or a delegate allowed to handle offers
     #[account(mut)]
     pub seller: Signer<'info>,
 
     #[account(
         mut,
-        constraint = car.owner == seller.key() @ CarError::UnauthorizedAccess,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
         constraint = car.is_for_sale @ CarError::CarNotForSale
     )]
     pub car: Account<'info, CarAccount>,
 
+    // Required when the car is co-owned; other co-owners sign as remaining accounts
+    #[account(
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    // Required when the car is owned by an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+
     #[account(
         mut,
         constraint = buy_request.vin == vin @ CarError::InvalidBuyRequest,
@@ -32,6 +50,19 @@ pub struct RejectBuyRequest<'info> {
 }
 
 pub fn handler(ctx: Context<RejectBuyRequest>, vin: String) -> Result<()> {
+    require!(
+        is_car_action_authorized(
+            &ctx.accounts.car,
+            ctx.accounts.co_ownership.as_deref(),
+            ctx.accounts.organization.as_ref(),
+            &ctx.accounts.seller.key(),
+            ctx.remaining_accounts,
+            Clock::get()?.unix_timestamp,
+            DelegateAction::AcceptOffers
+        )?,
+        CarError::UnauthorizedAccess
+    );
+
     let buy_request = &mut ctx.accounts.buy_request;
     
     // Update buy request status to rejected
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, Lien};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct ReleaseLien<'info> {
+    #[account(
+        mut,
+        seeds = [b"lien", car.key().as_ref()],
+        bump = lien.bump,
+        has_one = lender,
+        close = lender
+    )]
+    pub lien: Account<'info, Lien>,
+
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub lender: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReleaseLien>, vin: String) -> Result<()> {
+    ctx.accounts.car.lienholder = None;
+
+    msg!("🏦 Lien on car {} released", vin);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CoOwnership, Organization, UserAccount};
+use crate::errors::CarError;
+use crate::events::VehicleReportedStolen;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::{has_stake_in_car, is_law_enforcement};
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct ReportStolen<'info> {
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    // The owner, a co-owner, a fleet manager, or a police officer
+    pub reporter: Signer<'info>,
+
+    // Required when reporting as law enforcement
+    #[account(
+        seeds = [b"user", reporter.key().as_ref(), reporter_user.user_name.as_bytes()],
+        bump = reporter_user.bump
+    )]
+    pub reporter_user: Option<Account<'info, UserAccount>>,
+
+    #[account(
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+pub fn handler(ctx: Context<ReportStolen>, vin: String) -> Result<()> {
+    let car = &mut ctx.accounts.car;
+    let reporter = ctx.accounts.reporter.key();
+
+    require!(
+        is_law_enforcement(ctx.accounts.reporter_user.as_deref(), &reporter)
+            || has_stake_in_car(
+                car,
+                ctx.accounts.co_ownership.as_deref(),
+                ctx.accounts.organization.as_ref(),
+                &reporter,
+            ),
+        CarError::UnauthorizedTheftReport
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    car.is_stolen = true;
+    car.stolen_reported_by = Some(reporter);
+    car.stolen_reported_at = Some(now);
+
+    // Take the car off the market so open buy requests can be reclaimed,
+    // and stop anyone acting for the owner
+    car.is_for_sale = false;
+    car.sale_price = None;
+    car.delegate = None;
+
+    emit!(VehicleReportedStolen {
+        car: car.key(),
+        vin: vin.clone(),
+        reported_by: reporter,
+        timestamp: now,
+    });
+
+    msg!("🚨 Car {} reported stolen by {}", vin, reporter);
+
+    Ok(())
+}
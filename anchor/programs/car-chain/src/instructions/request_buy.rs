@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use crate::state::{BuyRequest, BuyRequestStatus, CarAccount, UserAccount, VerificationStatus };
+use crate::state::{BuyRequest, BuyRequestStatus, CarAccount, Organization, TaxConfig, UserAccount, VerificationStatus };
 use crate::errors::CarError;
 use crate::errors::CustomError;
+use crate::errors::TaxError;
 use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::acquiring_owner;
 
 #[derive(Accounts)]
 #[instruction(vin: String)]
@@ -10,8 +12,12 @@ pub struct SendBuyRequest<'info> {
     #[account(
         init,
         payer = buyer,
-        space = 8 + 32 + 32 + 32 + 8 + 4 + 200 + 1, // Discriminator + vin + buyer + owner + amount + msg_len + msg + status
-        seeds = [b"buy_request", vin.as_bytes(), buyer.key().as_ref()],
+        space = BuyRequest::LEN,
+        seeds = [
+            b"buy_request",
+            vin.as_bytes(),
+            organization.as_ref().map_or(buyer.key(), |organization| organization.key()).as_ref(),
+        ],
         bump,
     )]
     pub buy_request: Account<'info, BuyRequest>,
@@ -21,6 +27,8 @@ pub struct SendBuyRequest<'info> {
         bump,
         constraint = car.is_for_sale @ CarError::CarNotForSale,
         constraint = car.sale_price.is_some() @ CarError::SalePriceNotSet,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+        constraint = car.is_active @ CarError::CarDeregistered,
     )]
     pub car: Account<'info, CarAccount>,
     
@@ -30,9 +38,20 @@ pub struct SendBuyRequest<'info> {
         constraint = buyer_pda.verification_status == VerificationStatus::Verified @ CustomError::UserNotVerified
     )]
     pub buyer_pda: Account<'info, UserAccount>,
+
+    #[account(seeds = [b"tax_config"], bump = tax_config.bump)]
+    pub tax_config: Account<'info, TaxConfig>,
     
+    // The buyer, or an admin buying for their organization
     #[account(mut)]
     pub buyer: Signer<'info>,
+
+    // Set when buying for an organization, which becomes the buyer of record
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
     
     pub system_program: Program<'info, System>,
 }
@@ -41,23 +60,43 @@ pub fn handler(
     ctx: Context<SendBuyRequest>,
     vin: String,
     message: Option<String>,
+    acknowledged_accident_count: u16,
 ) -> Result<()> {
     let car = &ctx.accounts.car;
+    let buyer = acquiring_owner(&ctx.accounts.buyer.key(), ctx.accounts.organization.as_ref())?;
     let buy_request = &mut ctx.accounts.buy_request;
     
     // Get the sale price and ensure it exists
     let transfer_amount = car.sale_price.ok_or(CarError::SalePriceNotSet)?;
     
     // Ensure buyer is not trying to buy their own car
-    require!(ctx.accounts.buyer.key() != car.owner, CarError::CannotBuyOwnCar);
+    require!(buyer != car.owner, CarError::CannotBuyOwnCar);
+
+    // The buyer must have seen the car's full accident history
+    require!(
+        acknowledged_accident_count == car.accident_count,
+        CarError::AccidentHistoryNotAcknowledged
+    );
+
+    // The transfer tax is escrowed with the price so the buyer pays exactly
+    // what the tax table said when the offer was made
+    let vehicle_age = car.age_at(Clock::get()?.unix_timestamp);
+    let tax_rate_bps = ctx.accounts.tax_config.rate_bps(vehicle_age, transfer_amount);
+    let tax_amount = ctx.accounts.tax_config.sale_tax(vehicle_age, transfer_amount);
+    let escrow_amount = transfer_amount
+        .checked_add(tax_amount)
+        .ok_or(TaxError::TaxOverflow)?;
     
     // Initialize the buy request
     buy_request.initialize(
         vin.clone(),
-        ctx.accounts.buyer.key(),
+        buyer,
         car.owner,
         transfer_amount,
         message,
+        car.listing_version,
+        tax_amount,
+        tax_rate_bps,
     )?;
 
 
@@ -68,9 +107,9 @@ pub fn handler(
             to: ctx.accounts.buy_request.to_account_info(),
         },
     );
-    anchor_lang::system_program::transfer(cpi_context, transfer_amount)?;
+    anchor_lang::system_program::transfer(cpi_context, escrow_amount)?;
     
-    msg!("📝 New buy request for car {} with price {} lamports", vin, transfer_amount);
+    msg!("📝 New buy request for car {} with price {} lamports (+{} lamports tax)", vin, transfer_amount, tax_amount);
     
     Ok(())
 }
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, InspectionRequest, InspectionRequestStatus, InspectionType};
+use crate::errors::{CarError, CustomError, InspectionError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct RequestInspection<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = InspectionRequest::LEN,
+        seeds = [b"inspection_request", car.key().as_ref()],
+        bump
+    )]
+    pub inspection_request: Account<'info, InspectionRequest>,
+
+    #[account(
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = car.owner == owner.key() @ CustomError::NotCarOwner,
+        constraint = car.is_active @ CarError::CarDeregistered,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<RequestInspection>,
+    vin: String,
+    requested_type: InspectionType,
+    window_start: i64,
+    window_end: i64,
+    fee: u64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        window_start < window_end && window_end > now,
+        InspectionError::InvalidInspectionWindow
+    );
+
+    let request = &mut ctx.accounts.inspection_request;
+    request.car = ctx.accounts.car.key();
+    request.owner = ctx.accounts.owner.key();
+    request.requested_type = requested_type;
+    request.window_start = window_start;
+    request.window_end = window_end;
+    request.fee = fee;
+    request.inspector = None;
+    request.status = InspectionRequestStatus::Open;
+    request.created_at = now;
+    request.claimed_at = None;
+    request.bump = ctx.bumps.inspection_request;
+
+    // Escrow the inspector's fee
+    if fee > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.inspection_request.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, fee)?;
+    }
+
+    msg!("🗓️ Inspection requested for car {} with a fee of {} lamports", vin, fee);
+
+    Ok(())
+}
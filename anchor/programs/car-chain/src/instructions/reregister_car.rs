@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, DeregistrationRecord, OwnershipRecord, TransferMechanism};
+use crate::errors::{CustomError, DeregistrationError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+// A re-imported vehicle gets its old car account back rather than a fresh
+// one, so its ownership and inspection history carry over.
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct ReregisterCar<'info> {
+    #[account(
+        mut,
+        seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
+        bump = car.bump,
+        constraint = !car.is_active @ DeregistrationError::CarNotDeregistered,
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"deregistration", car.key().as_ref(), &car.registration_count.to_le_bytes()],
+        bump = deregistration_record.bump,
+        constraint = car.last_deregistration == Some(deregistration_record.key()) @ DeregistrationError::DeregistrationRecordMismatch,
+        constraint = deregistration_record.reason.allows_reregistration() @ DeregistrationError::ReregistrationNotAllowed,
+    )]
+    pub deregistration_record: Account<'info, DeregistrationRecord>,
+
+    #[account(
+        init,
+        payer = government,
+        space = OwnershipRecord::LEN,
+        seeds = [b"ownership_record", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub ownership_record: Account<'info, OwnershipRecord>,
+
+    #[account(
+        mut,
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ReregisterCar>,
+    vin: String,
+    owner: Pubkey,
+    mileage: u32,
+) -> Result<()> {
+    let car = &mut ctx.accounts.car;
+    let now = Clock::get()?.unix_timestamp;
+
+    ctx.accounts.deregistration_record.reregistered_at = Some(now);
+
+    // The importer is recorded as the next owner in the provenance chain
+    let transfer_index = car.next_transfer_index()?;
+    ctx.accounts.ownership_record.initialize(
+        car.key(),
+        transfer_index,
+        car.owner,
+        owner,
+        None,
+        TransferMechanism::Reimport,
+        ctx.bumps.ownership_record,
+    )?;
+
+    car.registration_count = car.registration_count
+        .checked_add(1)
+        .ok_or(DeregistrationError::RegistrationCountOverflow)?;
+    car.owner = owner;
+    car.registered_by = ctx.accounts.government.key();
+    car.registration_date = Some(now);
+    car.mileage = mileage;
+    car.is_active = true;
+    car.last_deregistration = None;
+
+    msg!("🔁 Car {} re-registered to {}", vin, owner);
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    CarAccount, CarReport, ConformityReport, DisputeResolution, InspectionConfig, InspectorStats, ReportDispute,
+    ReportStatus,
+};
+use crate::errors::{CustomError, InspectionError, ReportStatusError};
+use crate::events::InspectorSuspended;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+// Either `car_report` or `conformity_report` is given, matching the dispute.
+// `inspector_stats` of the report's inspector comes with `car_report`.
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"report_dispute", report_dispute.report.as_ref()],
+        bump = report_dispute.bump,
+        constraint = report_dispute.resolution.is_none() @ ReportStatusError::ReportNotDisputed
+    )]
+    pub report_dispute: Account<'info, ReportDispute>,
+
+    #[account(mut)]
+    pub car_report: Option<Account<'info, CarReport>>,
+
+    #[account(mut)]
+    pub conformity_report: Option<Account<'info, ConformityReport>>,
+
+    #[account(mut)]
+    pub inspector_stats: Option<Account<'info, InspectorStats>>,
+
+    #[account(
+        mut,
+        constraint = car.key() == report_dispute.car @ ReportStatusError::InvalidReportAccounts
+    )]
+    pub car: Account<'info, CarAccount>,
+
+    #[account(seeds = [b"inspection_config"], bump = inspection_config.bump)]
+    pub inspection_config: Account<'info, InspectionConfig>,
+
+    #[account(
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ResolveDispute>, resolution: DisputeResolution) -> Result<()> {
+    let dispute = &mut ctx.accounts.report_dispute;
+    let now = Clock::get()?.unix_timestamp;
+    let status = match resolution {
+        DisputeResolution::Upheld => ReportStatus::Upheld,
+        DisputeResolution::Voided => ReportStatus::Voided,
+    };
+
+    match (&mut ctx.accounts.car_report, &mut ctx.accounts.conformity_report) {
+        (Some(report), None) => {
+            require_keys_eq!(report.key(), dispute.report, ReportStatusError::InvalidReportAccounts);
+            require!(report.status == ReportStatus::Disputed, ReportStatusError::ReportNotDisputed);
+            report.status = status;
+
+            let stats = ctx.accounts.inspector_stats
+                .as_mut()
+                .ok_or(InspectionError::InspectorStatsMismatch)?;
+            require_keys_eq!(stats.inspector, report.inspector, InspectionError::InspectorStatsMismatch);
+            let suspended = stats.record_dispute(
+                status == ReportStatus::Voided,
+                ctx.accounts.inspection_config.max_voided_reports,
+                now,
+            );
+            if suspended {
+                emit!(InspectorSuspended {
+                    inspector: stats.inspector,
+                    reports_voided: stats.reports_voided,
+                    timestamp: now,
+                });
+            }
+
+            // An upheld inspection report counts as accepted
+            if status == ReportStatus::Upheld {
+                ctx.accounts.car.apply_inspection_report(
+                    report.key(),
+                    report,
+                    &ctx.accounts.inspection_config,
+                    now,
+                );
+            }
+        }
+        (None, Some(report)) => {
+            require_keys_eq!(report.key(), dispute.report, ReportStatusError::InvalidReportAccounts);
+            require!(report.status == ReportStatus::Disputed, ReportStatusError::ReportNotDisputed);
+            report.status = status;
+        }
+        _ => return err!(ReportStatusError::InvalidReportAccounts),
+    }
+
+    dispute.resolution = Some(resolution);
+    dispute.resolved_by = Some(ctx.accounts.government.key());
+    dispute.resolved_at = Some(now);
+
+    msg!("⚖️ Dispute of report {} resolved", dispute.report);
+
+    Ok(())
+}
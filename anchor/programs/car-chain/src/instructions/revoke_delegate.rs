@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::car::CarAccount,
+    errors::{CarError, CustomError},
+};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+#[instruction(vin: String)]
+pub struct RevokeDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"car",
+            GOVERNMENT_AUTHORITY.as_ref(),
+            vin.as_bytes()
+        ],
+        bump = car_account.bump,
+        constraint = car_account.delegate.is_some() @ CarError::NoDelegate
+    )]
+    pub car_account: Account<'info, CarAccount>,
+
+    #[account(
+        constraint = owner.key() == car_account.owner @ CustomError::NotCarOwner
+    )]
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeDelegate>, vin: String) -> Result<()> {
+    ctx.accounts.car_account.delegate = None;
+
+    msg!("Delegation on car {} revoked", vin);
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CarReport, ConformityReport, InspectionStatus, ReportStatus};
+use crate::errors::ReportStatusError;
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+// Either `car_report` or `conformity_report` is given, never both.
+// The issuing inspector or expert, or the government, may revoke.
+#[derive(Accounts)]
+pub struct RevokeReport<'info> {
+    #[account(mut)]
+    pub car_report: Option<Account<'info, CarReport>>,
+
+    #[account(mut)]
+    pub conformity_report: Option<Account<'info, ConformityReport>>,
+
+    #[account(mut)]
+    pub car: Account<'info, CarAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeReport>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let is_government = authority == GOVERNMENT_AUTHORITY;
+    let car = &mut ctx.accounts.car;
+
+    let report_key = match (&mut ctx.accounts.car_report, &mut ctx.accounts.conformity_report) {
+        (Some(report), None) => {
+            let report_key = report.key();
+            require_keys_eq!(report.car, car.key(), ReportStatusError::InvalidReportAccounts);
+            require!(
+                is_government || report.is_issued_by(&report_key, &authority),
+                ReportStatusError::NotReportIssuer
+            );
+            require!(report.status.is_live(), ReportStatusError::ReportNotLive);
+            report.status = ReportStatus::Revoked;
+
+            // The car no longer has a valid inspection if this was its record
+            if car.latest_inspection_report == Some(report_key.to_string()) {
+                car.inspection_status = InspectionStatus::Pending;
+                car.inspection_expires_at = None;
+            }
+            report_key
+        }
+        (None, Some(report)) => {
+            let report_key = report.key();
+            require_keys_eq!(report.car, car.key(), ReportStatusError::InvalidReportAccounts);
+            require!(
+                is_government || report.is_issued_by(&report_key, &authority),
+                ReportStatusError::NotReportIssuer
+            );
+            require!(report.status.is_live(), ReportStatusError::ReportNotLive);
+            report.status = ReportStatus::Revoked;
+            report_key
+        }
+        _ => return err!(ReportStatusError::InvalidReportAccounts),
+    };
+
+    msg!("🚫 Report {} revoked by {}", report_key, authority);
+
+    Ok(())
+}
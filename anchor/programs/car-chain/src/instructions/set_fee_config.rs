@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::fee_config::{FeeConfig, Treasury},
+    errors::{CustomError, FeeError},
+};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+pub struct SetFeeConfig<'info> {
+    #[account(
+        init_if_needed,
+        payer = government,
+        space = FeeConfig::LEN,
+        seeds = [b"fee_config"],
+        bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    // Fees are paid into this PDA; it is created together with the config
+    #[account(
+        init_if_needed,
+        payer = government,
+        space = Treasury::LEN,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetFeeConfig>,
+    sale_fee_bps: u16,
+    registration_fee: u64,
+    transfer_fee: u64,
+) -> Result<()> {
+    require!(sale_fee_bps <= FeeConfig::MAX_BPS, FeeError::InvalidFeeBps);
+
+    let fee_config = &mut ctx.accounts.fee_config;
+    fee_config.sale_fee_bps = sale_fee_bps;
+    fee_config.registration_fee = registration_fee;
+    fee_config.transfer_fee = transfer_fee;
+    fee_config.updated_at = Clock::get()?.unix_timestamp;
+    fee_config.bump = ctx.bumps.fee_config;
+
+    ctx.accounts.treasury.bump = ctx.bumps.treasury;
+
+    msg!(
+        "Fee schedule updated: sale {} bps, registration {} lamports, transfer {} lamports",
+        sale_fee_bps,
+        registration_fee,
+        transfer_fee
+    );
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
 use anchor_lang::prelude::*;
 use crate::{
-    state::car::{CarAccount},
-    errors::CustomError,
+    state::car::{CarAccount, DelegateAction},
+    state::co_ownership::CoOwnership,
+    state::organization::Organization,
+    state::inspection_config::InspectionConfig,
+    errors::{CarError, CustomError, InspectionError, InstallmentError},
 };
 use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::is_car_action_authorized;
 
 #[derive(Accounts)]
 #[instruction(vin: String, price: u64)]
@@ -16,16 +20,34 @@ pub struct SetCarForSale<'info> {
             GOVERNMENT_AUTHORITY.as_ref(),
             vin.as_bytes()
         ],
-        bump
+        bump,
+        constraint = !car_account.is_for_sale @ CarError::CarAlreadyForSale,
+        constraint = car_account.installment_plan.is_none() @ InstallmentError::CarUnderInstallmentPlan,
+        constraint = !car_account.is_stolen @ CarError::CarReportedStolen,
+        constraint = car_account.is_active @ CarError::CarDeregistered,
     )]
     pub car_account: Account<'info, CarAccount>,
     
-    // The owner of the car
+    // The owner of the car, or a delegate allowed to list it
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Required when the car is co-owned; other co-owners sign as remaining accounts
     #[account(
-        mut,
-        constraint = owner.key() == car_account.owner @ CustomError::NotCarOwner
+        seeds = [b"co_ownership", car_account.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    // Required when the car is owned by an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
     )]
-    pub owner: Signer<'info>,
+    pub organization: Option<Account<'info, Organization>>,
+
+    #[account(seeds = [b"inspection_config"], bump = inspection_config.bump)]
+    pub inspection_config: Account<'info, InspectionConfig>,
 
     system_program: Program<'info, System>,
 }
@@ -36,10 +58,33 @@ pub fn handler(
     price: u64,
 ) -> Result<()> {
     let car_account = &mut ctx.accounts.car_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        is_car_action_authorized(
+            car_account,
+            ctx.accounts.co_ownership.as_deref(),
+            ctx.accounts.organization.as_ref(),
+            &ctx.accounts.authority.key(),
+            ctx.remaining_accounts,
+            now,
+            DelegateAction::List
+        )?,
+        CustomError::NotCarOwner
+    );
+
+    require!(
+        !ctx.accounts.inspection_config.require_valid_inspection_for_sale
+            || car_account.has_valid_inspection(now),
+        InspectionError::ValidInspectionRequired
+    );
     
     // Update car sale status and price
     car_account.is_for_sale = true;
     car_account.sale_price = Some(price);
+    car_account.listing_version = car_account.listing_version
+        .checked_add(1)
+        .ok_or(CarError::ListingVersionOverflow)?;
     
     msg!("Car {} has been set for sale at {} lamports", vin, price);
 
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::inspection_config::{InspectionConfig, InspectionValidityRule},
+    errors::{CustomError, InspectionError},
+};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+pub struct SetInspectionConfig<'info> {
+    #[account(
+        init_if_needed,
+        payer = government,
+        space = InspectionConfig::LEN,
+        seeds = [b"inspection_config"],
+        bump
+    )]
+    pub inspection_config: Account<'info, InspectionConfig>,
+
+    #[account(
+        mut,
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetInspectionConfig>,
+    validity_rules: Vec<InspectionValidityRule>,
+    default_validity_seconds: i64,
+    require_valid_inspection_for_sale: bool,
+    max_voided_reports: u32,
+) -> Result<()> {
+    require!(validity_rules.len() <= InspectionConfig::MAX_RULES, InspectionError::TooManyRules);
+    require!(
+        default_validity_seconds > 0 && validity_rules.iter().all(|rule| rule.validity_seconds > 0),
+        InspectionError::InvalidValidityPeriod
+    );
+    require!(
+        validity_rules.iter().enumerate().all(|(i, rule)| {
+            validity_rules[..i]
+                .iter()
+                .filter(|earlier| earlier.category == rule.category)
+                .all(|earlier| earlier.max_age < rule.max_age)
+        }),
+        InspectionError::RulesNotSorted
+    );
+
+    let inspection_config = &mut ctx.accounts.inspection_config;
+    inspection_config.authority = ctx.accounts.government.key();
+    inspection_config.validity_rules = validity_rules;
+    inspection_config.default_validity_seconds = default_validity_seconds;
+    inspection_config.require_valid_inspection_for_sale = require_valid_inspection_for_sale;
+    inspection_config.max_voided_reports = max_voided_reports;
+    inspection_config.updated_at = Clock::get()?.unix_timestamp;
+    inspection_config.bump = ctx.bumps.inspection_config;
+
+    msg!("🏛️ Inspection validity periods updated");
+
+    Ok(())
+}
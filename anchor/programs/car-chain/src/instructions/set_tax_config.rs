@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::tax_config::{AgeTaxBracket, PriceTaxBracket, TaxConfig, TaxTreasury},
+    errors::{CustomError, TaxError},
+};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+pub struct SetTaxConfig<'info> {
+    #[account(
+        init_if_needed,
+        payer = government,
+        space = TaxConfig::LEN,
+        seeds = [b"tax_config"],
+        bump
+    )]
+    pub tax_config: Account<'info, TaxConfig>,
+
+    // Transfer tax is paid into this PDA; it is created together with the config
+    #[account(
+        init_if_needed,
+        payer = government,
+        space = TaxTreasury::LEN,
+        seeds = [b"tax_treasury"],
+        bump
+    )]
+    pub tax_treasury: Account<'info, TaxTreasury>,
+
+    #[account(
+        mut,
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SetTaxConfig>,
+    age_brackets: Vec<AgeTaxBracket>,
+    price_brackets: Vec<PriceTaxBracket>,
+    flat_transfer_tax: u64,
+) -> Result<()> {
+    require!(age_brackets.len() <= TaxConfig::MAX_BRACKETS, TaxError::TooManyBrackets);
+    require!(price_brackets.len() <= TaxConfig::MAX_BRACKETS, TaxError::TooManyBrackets);
+    require!(
+        age_brackets.iter().all(|bracket| bracket.rate_bps <= TaxConfig::MAX_BPS)
+            && price_brackets.iter().all(|bracket| bracket.surcharge_bps <= TaxConfig::MAX_BPS),
+        TaxError::InvalidTaxRate
+    );
+    require!(
+        age_brackets.windows(2).all(|pair| pair[0].max_age < pair[1].max_age)
+            && price_brackets.windows(2).all(|pair| pair[0].min_price < pair[1].min_price),
+        TaxError::BracketsNotSorted
+    );
+
+    let tax_config = &mut ctx.accounts.tax_config;
+    tax_config.age_brackets = age_brackets;
+    tax_config.price_brackets = price_brackets;
+    tax_config.flat_transfer_tax = flat_transfer_tax;
+    tax_config.updated_at = Clock::get()?.unix_timestamp;
+    tax_config.bump = ctx.bumps.tax_config;
+
+    ctx.accounts.tax_treasury.bump = ctx.bumps.tax_treasury;
+
+    msg!("🏛️ Transfer tax table updated");
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::state::{BuyRequest, BuyRequestStatus, CarAccount, UserAccount, VerificationStatus};
-use crate::errors::{CarError, CustomError};
+use crate::state::{BuyRequest, BuyRequestStatus, CarAccount, CoOwnership, DelegateAction, FeeConfig, FeeKind, Organization, OwnershipRecord, TaxConfig, TaxReceipt, TaxTreasury, TransferMechanism, Treasury, UserAccount, VerificationStatus};
+use crate::errors::{CarError, CustomError, DealerError, InstallmentError, LienError};
+use crate::events::FeeCollected;
 use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::{acquiring_owner, is_car_action_authorized};
 
 #[derive(Accounts)]
 #[instruction(vin: String,user_name: String)]
@@ -9,12 +11,19 @@ pub struct TransferCar<'info> {
     #[account(mut,
         seeds = [b"car", GOVERNMENT_AUTHORITY.as_ref(), vin.as_bytes()],
         bump = car.bump,
+        constraint = car.consigned_to.is_none() @ DealerError::CarConsigned,
+        constraint = car.lienholder.is_none() @ LienError::CarHasLien,
+        constraint = car.installment_plan.is_none() @ InstallmentError::CarUnderInstallmentPlan,
+        constraint = !car.is_stolen @ CarError::CarReportedStolen,
+        constraint = car.is_active @ CarError::CarDeregistered,
     )]
     pub car: Account<'info, CarAccount>,
     
+    // The current owner, or a delegate allowed to transfer the car
     #[account(mut)]
-    pub current_owner: Signer<'info>,
+    pub authority: Signer<'info>,
     
+    // The new owner, or an admin taking the car for their organization
     #[account(mut)]
     pub new_owner: Signer<'info>,
     #[account(mut
@@ -25,15 +34,76 @@ pub struct TransferCar<'info> {
     ]
     , bump)]
     pub new_owner_pda: Account<'info, UserAccount>,
+
+    #[account(seeds = [b"fee_config"], bump = fee_config.bump)]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(seeds = [b"tax_config"], bump = tax_config.bump)]
+    pub tax_config: Account<'info, TaxConfig>,
+
+    #[account(mut, seeds = [b"tax_treasury"], bump = tax_treasury.bump)]
+    pub tax_treasury: Account<'info, TaxTreasury>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = TaxReceipt::LEN,
+        seeds = [b"tax_receipt", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub tax_receipt: Account<'info, TaxReceipt>,
+
+    #[account(
+        init,
+        payer = new_owner,
+        space = OwnershipRecord::LEN,
+        seeds = [b"ownership_record", car.key().as_ref(), &car.transfer_count.to_le_bytes()],
+        bump
+    )]
+    pub ownership_record: Account<'info, OwnershipRecord>,
+
+    // Required when the car is co-owned; other co-owners sign as remaining accounts
+    #[account(
+        mut,
+        seeds = [b"co_ownership", car.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    // Required when the car is owned by an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+
+    // Required when the car is transferred to an organization
+    #[account(
+        seeds = [b"organization", new_owner_organization.name.as_bytes()],
+        bump = new_owner_organization.bump
+    )]
+    pub new_owner_organization: Option<Account<'info, Organization>>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<TransferCar>, vin: String,user_name: String) -> Result<()> {
     let car = &mut ctx.accounts.car;
     
-    // Verify the current owner
+    // Verify the current owner or delegate
     require!(
-        car.owner == ctx.accounts.current_owner.key(),
+        is_car_action_authorized(
+            car,
+            ctx.accounts.co_ownership.as_deref(),
+            ctx.accounts.organization.as_ref(),
+            &ctx.accounts.authority.key(),
+            ctx.remaining_accounts,
+            Clock::get()?.unix_timestamp,
+            DelegateAction::Transfer
+        )?,
         CarError::UnauthorizedAccess
     );
     // Get the PDA account for the new owner
@@ -42,8 +112,88 @@ pub fn handler(ctx: Context<TransferCar>, vin: String,user_name: String) -> Resu
         ctx.accounts.new_owner_pda.verification_status == VerificationStatus::Verified,
         CustomError::UserNotVerified
     );
-    // Transfer ownership
-    car.owner = ctx.accounts.new_owner.key();
+    let new_owner = acquiring_owner(
+        &ctx.accounts.new_owner.key(),
+        ctx.accounts.new_owner_organization.as_ref(),
+    )?;
+
+    // Transfer fee is paid by the signer on the current owner's side
+    let transfer_fee = ctx.accounts.fee_config.transfer_fee;
+    if transfer_fee > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, transfer_fee)?;
+        ctx.accounts.treasury.record_fee(transfer_fee)?;
+
+        emit!(FeeCollected {
+            kind: FeeKind::Transfer,
+            vin: vin.clone(),
+            payer: ctx.accounts.authority.key(),
+            amount: transfer_fee,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    // Transfer tax is paid by the new owner; without a sale price the flat rate applies
+    let tax_amount = ctx.accounts.tax_config.flat_transfer_tax;
+    if tax_amount > 0 {
+        let cpi_context = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: ctx.accounts.new_owner.to_account_info(),
+                to: ctx.accounts.tax_treasury.to_account_info(),
+            },
+        );
+        anchor_lang::system_program::transfer(cpi_context, tax_amount)?;
+        ctx.accounts.tax_treasury.record_tax(tax_amount)?;
+    }
+
+    let transfer_index = car.next_transfer_index()?;
+    let vehicle_age = car.age_at(Clock::get()?.unix_timestamp);
+    ctx.accounts.tax_receipt.initialize(
+        transfer_index,
+        car.key(),
+        vin.clone(),
+        new_owner,
+        car.owner,
+        None,
+        vehicle_age,
+        0,
+        tax_amount,
+        ctx.bumps.tax_receipt,
+    )?;
+
+    ctx.accounts.ownership_record.initialize(
+        car.key(),
+        transfer_index,
+        car.owner,
+        new_owner,
+        None,
+        TransferMechanism::DirectTransfer,
+        ctx.bumps.ownership_record,
+    )?;
+
+    // Transfer ownership; listings and delegations belonged to the previous owner.
+    // Bumping the version lets buyers reclaim requests made against the old listing
+    car.owner = new_owner;
+    car.is_for_sale = false;
+    car.sale_price = None;
+    car.listing_version = car.listing_version
+        .checked_add(1)
+        .ok_or(CarError::ListingVersionOverflow)?;
+    car.delegate = None;
+
+    if let Some(co_ownership) = &ctx.accounts.co_ownership {
+        if car.is_co_owned {
+            co_ownership.close(ctx.accounts.authority.to_account_info())?;
+            car.is_co_owned = false;
+        }
+    }
 
     msg!("Car with VIN {} has been transferred to {}", vin, car.owner);
 
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{AccidentRecord, RepairStatus, UserAccount, VerificationStatus};
+use crate::errors::AccidentError;
+
+// Repairs are confirmed by any verified inspector, insurer or police officer,
+// not necessarily the one who recorded the accident.
+#[derive(Accounts)]
+pub struct UpdateAccidentRepair<'info> {
+    #[account(
+        mut,
+        seeds = [b"accident", accident_record.car.as_ref(), &accident_record.accident_index.to_le_bytes()],
+        bump = accident_record.bump
+    )]
+    pub accident_record: Account<'info, AccidentRecord>,
+
+    #[account(
+        seeds = [b"user", authority.key().as_ref(), authority_user.user_name.as_bytes()],
+        bump = authority_user.bump,
+        constraint = AccidentRecord::can_report(&authority_user.role) @ AccidentError::NotAuthorizedReporter,
+        constraint = authority_user.verification_status == VerificationStatus::Verified @ AccidentError::ReporterNotVerified
+    )]
+    pub authority_user: Account<'info, UserAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateAccidentRepair>, repair_status: RepairStatus) -> Result<()> {
+    let record = &mut ctx.accounts.accident_record;
+
+    require!(
+        (repair_status as u8) > (record.repair_status as u8),
+        AccidentError::InvalidRepairStatus
+    );
+
+    record.repair_status = repair_status;
+    record.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("🔧 Repair status updated for accident #{}", record.accident_index);
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::{InspectionCenter, UserAccount, UserRoles, VerificationStatus};
+use crate::errors::InspectionCenterError;
+
+#[derive(Accounts)]
+pub struct UpdateCenterInspectors<'info> {
+    #[account(
+        mut,
+        seeds = [b"inspection_center", inspection_center.licence_number.as_bytes()],
+        bump = inspection_center.bump,
+        has_one = authority
+    )]
+    pub inspection_center: Account<'info, InspectionCenter>,
+
+    #[account(
+        constraint = inspector.role == UserRoles::Inspector @ InspectionCenterError::NotInspector
+    )]
+    pub inspector: Account<'info, UserAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateCenterInspectors>,
+    add: bool,          // true = إضافة، false = إزالة
+) -> Result<()> {
+    let center = &mut ctx.accounts.inspection_center;
+    let inspector = ctx.accounts.inspector.key();
+
+    if add {
+        require!(
+            ctx.accounts.inspector.verification_status == VerificationStatus::Verified,
+            InspectionCenterError::NotInspector
+        );
+        require!(!center.has_inspector(&inspector), InspectionCenterError::InspectorAlreadyMember);
+        require!(center.inspectors.len() < InspectionCenter::MAX_INSPECTORS, InspectionCenterError::TooManyInspectors);
+        center.inspectors.push(inspector);
+
+        msg!("✅ Inspector {} joined centre {}", inspector, center.licence_number);
+    } else {
+        let index = center.inspectors
+            .iter()
+            .position(|key| *key == inspector)
+            .ok_or(InspectionCenterError::InspectorNotMember)?;
+        center.inspectors.remove(index);
+
+        msg!("❌ Inspector {} left centre {}", inspector, center.licence_number);
+    }
+    center.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::DealerAccount;
+use crate::errors::DealerError;
+
+#[derive(Accounts)]
+pub struct UpdateDealerStaff<'info> {
+    #[account(
+        mut,
+        seeds = [b"dealer", authority.key().as_ref()],
+        bump = dealer.bump,
+        has_one = authority
+    )]
+    pub dealer: Account<'info, DealerAccount>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateDealerStaff>,
+    staff: Pubkey,
+    add: bool,          // true = إضافة، false = إزالة
+) -> Result<()> {
+    let dealer = &mut ctx.accounts.dealer;
+
+    if add {
+        require!(!dealer.staff.contains(&staff), DealerError::StaffAlreadyAdded);
+        require!(dealer.staff.len() < DealerAccount::MAX_STAFF, DealerError::TooManyStaff);
+        dealer.staff.push(staff);
+
+        msg!("✅ Staff {} added to dealer", staff);
+    } else {
+        let index = dealer.staff
+            .iter()
+            .position(|key| *key == staff)
+            .ok_or(DealerError::StaffNotFound)?;
+        dealer.staff.remove(index);
+
+        msg!("❌ Staff {} removed from dealer", staff);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{CenterLicenceStatus, InspectionCenter, InspectionType};
+use crate::errors::{CustomError, InspectionCenterError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+// Government changes to a centre's licence: capacity, approved inspection
+// types and suspension
+#[derive(Accounts)]
+pub struct UpdateInspectionCenter<'info> {
+    #[account(
+        mut,
+        seeds = [b"inspection_center", inspection_center.licence_number.as_bytes()],
+        bump = inspection_center.bump
+    )]
+    pub inspection_center: Account<'info, InspectionCenter>,
+
+    #[account(
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateInspectionCenter>,
+    capacity: u16,
+    approved_inspection_types: Vec<InspectionType>,
+    licence_status: CenterLicenceStatus,
+) -> Result<()> {
+    require!(capacity > 0, InspectionCenterError::InvalidCapacity);
+    require!(
+        InspectionCenter::valid_inspection_types(&approved_inspection_types),
+        InspectionCenterError::InvalidInspectionTypes
+    );
+
+    let center = &mut ctx.accounts.inspection_center;
+    center.capacity = capacity;
+    center.approved_inspection_types = approved_inspection_types;
+    center.licence_status = licence_status;
+    center.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("🏛️ Inspection centre {} updated", center.licence_number);
+
+    Ok(())
+}
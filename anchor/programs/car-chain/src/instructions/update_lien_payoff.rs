@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::Lien;
+use crate::errors::LienError;
+
+// Repayments happen off-chain; the lender keeps the payoff amount current.
+// The payoff only ever goes down, so a lender cannot inflate the debt that
+// sale proceeds are held back for
+#[derive(Accounts)]
+pub struct UpdateLienPayoff<'info> {
+    #[account(
+        mut,
+        seeds = [b"lien", lien.car.as_ref()],
+        bump = lien.bump,
+        has_one = lender
+    )]
+    pub lien: Account<'info, Lien>,
+
+    pub lender: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UpdateLienPayoff>, outstanding: u64) -> Result<()> {
+    let lien = &mut ctx.accounts.lien;
+
+    require!(
+        outstanding > 0 && outstanding <= lien.principal,
+        LienError::InvalidLienAmount
+    );
+    require!(outstanding < lien.outstanding, LienError::PayoffIncrease);
+
+    lien.outstanding = outstanding;
+    lien.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("🏦 Lien payoff updated to {} lamports", outstanding);
+
+    Ok(())
+}
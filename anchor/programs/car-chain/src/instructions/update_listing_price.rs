@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::car::{CarAccount, DelegateAction},
+    state::co_ownership::CoOwnership,
+    state::organization::Organization,
+    errors::{CarError, CustomError},
+};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+use crate::utils::validation::is_car_action_authorized;
+
+#[derive(Accounts)]
+#[instruction(vin: String, new_price: u64)]
+pub struct UpdateListingPrice<'info> {
+    // The listed car whose price changes
+    #[account(
+        mut,
+        seeds = [
+            b"car",
+            GOVERNMENT_AUTHORITY.as_ref(),
+            vin.as_bytes()
+        ],
+        bump,
+        constraint = car_account.is_for_sale @ CarError::CarNotForSale,
+        constraint = !car_account.is_stolen @ CarError::CarReportedStolen,
+        constraint = car_account.is_active @ CarError::CarDeregistered,
+    )]
+    pub car_account: Account<'info, CarAccount>,
+
+    // The owner of the car, or a delegate allowed to list it
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // Required when the car is co-owned; other co-owners sign as remaining accounts
+    #[account(
+        seeds = [b"co_ownership", car_account.key().as_ref()],
+        bump = co_ownership.bump
+    )]
+    pub co_ownership: Option<Account<'info, CoOwnership>>,
+
+    // Required when the car is owned by an organization
+    #[account(
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump
+    )]
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateListingPrice>,
+    vin: String,
+    new_price: u64,
+) -> Result<()> {
+    let car_account = &mut ctx.accounts.car_account;
+
+    require!(
+        is_car_action_authorized(
+            car_account,
+            ctx.accounts.co_ownership.as_deref(),
+            ctx.accounts.organization.as_ref(),
+            &ctx.accounts.authority.key(),
+            ctx.remaining_accounts,
+            Clock::get()?.unix_timestamp,
+            DelegateAction::List
+        )?,
+        CustomError::NotCarOwner
+    );
+
+    require!(car_account.sale_price != Some(new_price), CarError::PriceUnchanged);
+
+    // Bumping the version marks every pending buy request as made against an
+    // older price: the seller may still accept them at the escrowed amount,
+    // or the buyer may reclaim the escrow with `reclaim_buy_request`.
+    car_account.sale_price = Some(new_price);
+    car_account.listing_version = car_account.listing_version
+        .checked_add(1)
+        .ok_or(CarError::ListingVersionOverflow)?;
+
+    msg!(
+        "Car {} listing updated to {} lamports (version {})",
+        vin,
+        new_price,
+        car_account.listing_version
+    );
+
+    Ok(())
+}
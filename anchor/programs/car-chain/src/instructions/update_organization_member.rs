@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::{Organization, OrganizationMember, OrganizationRole};
+use crate::errors::OrganizationError;
+
+#[derive(Accounts)]
+pub struct UpdateOrganizationMember<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump,
+        constraint = organization.is_admin(&admin.key()) @ OrganizationError::NotOrganizationAdmin
+    )]
+    pub organization: Account<'info, Organization>,
+
+    pub admin: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<UpdateOrganizationMember>,
+    member: Pubkey,
+    role: Option<OrganizationRole>,   // None = إزالة العضو
+) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+    let index = organization.members.iter().position(|existing| existing.key == member);
+
+    match (index, role) {
+        (Some(index), Some(role)) => organization.members[index].role = role,
+        (None, Some(role)) => {
+            require!(
+                organization.members.len() < Organization::MAX_MEMBERS,
+                OrganizationError::TooManyMembers
+            );
+            organization.members.push(OrganizationMember { key: member, role });
+        }
+        (Some(index), None) => {
+            organization.members.remove(index);
+        }
+        (None, None) => return err!(OrganizationError::MemberNotFound),
+    }
+
+    require!(
+        organization.members.iter().any(|existing| existing.role == OrganizationRole::Admin),
+        OrganizationError::LastAdmin
+    );
+
+    msg!("🏢 Organization {} member {} updated", organization.name, member);
+
+    Ok(())
+}
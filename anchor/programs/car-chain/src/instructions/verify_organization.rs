@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::{Organization, VerificationStatus};
+use crate::errors::{CustomError, OrganizationError};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+pub struct VerifyOrganization<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump,
+        constraint = organization.verification_status == VerificationStatus::Pending @ OrganizationError::OrganizationAlreadyProcessed
+    )]
+    pub organization: Account<'info, Organization>,
+
+    #[account(
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<VerifyOrganization>, approve: bool) -> Result<()> {
+    let organization = &mut ctx.accounts.organization;
+
+    organization.verification_status = if approve {
+        VerificationStatus::Verified
+    } else {
+        VerificationStatus::Rejected
+    };
+    organization.verified_at = Some(Clock::get()?.unix_timestamp);
+
+    msg!("🏢 Organization {} processed, approved: {}", organization.name, approve);
+
+    Ok(())
+}
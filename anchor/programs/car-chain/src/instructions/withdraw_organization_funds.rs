@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::Organization;
+use crate::errors::OrganizationError;
+
+// Sale proceeds for organization-owned cars are paid to the organization PDA
+#[derive(Accounts)]
+pub struct WithdrawOrganizationFunds<'info> {
+    #[account(
+        mut,
+        seeds = [b"organization", organization.name.as_bytes()],
+        bump = organization.bump,
+        constraint = organization.is_admin(&admin.key()) @ OrganizationError::NotOrganizationAdmin
+    )]
+    pub organization: Account<'info, Organization>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: Any wallet chosen by the admin to receive the funds
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawOrganizationFunds>, amount: u64) -> Result<()> {
+    let organization_info = ctx.accounts.organization.to_account_info();
+
+    // The organization account must stay rent exempt
+    let reserve = Rent::get()?.minimum_balance(Organization::LEN);
+    let available = organization_info.lamports().saturating_sub(reserve);
+    require!(amount <= available, OrganizationError::InsufficientFunds);
+
+    **organization_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    msg!("🏢 {} lamports withdrawn from organization {}", amount, ctx.accounts.organization.name);
+
+    Ok(())
+}
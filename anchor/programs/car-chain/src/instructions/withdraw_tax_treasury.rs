@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::tax_config::TaxTreasury,
+    errors::{CustomError, TaxError},
+    events::TaxTreasuryWithdrawn,
+};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+pub struct WithdrawTaxTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"tax_treasury"],
+        bump = tax_treasury.bump
+    )]
+    pub tax_treasury: Account<'info, TaxTreasury>,
+
+    #[account(
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+
+    /// CHECK: Any wallet chosen by the government to receive the funds
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawTaxTreasury>, amount: u64) -> Result<()> {
+    let tax_treasury_info = ctx.accounts.tax_treasury.to_account_info();
+
+    // The tax treasury must stay rent exempt
+    let reserve = Rent::get()?.minimum_balance(TaxTreasury::LEN);
+    let available = tax_treasury_info.lamports().saturating_sub(reserve);
+    require!(amount <= available, TaxError::InsufficientTaxFunds);
+
+    **tax_treasury_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let tax_treasury = &mut ctx.accounts.tax_treasury;
+    tax_treasury.total_withdrawn = tax_treasury.total_withdrawn
+        .checked_add(amount)
+        .ok_or(TaxError::TaxOverflow)?;
+
+    emit!(TaxTreasuryWithdrawn {
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("🏛️ {} lamports of transfer tax withdrawn", amount);
+
+    Ok(())
+}
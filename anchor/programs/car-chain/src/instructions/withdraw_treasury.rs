@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::{
+    state::fee_config::Treasury,
+    errors::{CustomError, FeeError},
+    events::TreasuryWithdrawn,
+};
+use crate::utils::constants::GOVERNMENT_AUTHORITY;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        constraint = government.key() == GOVERNMENT_AUTHORITY @ CustomError::UnauthorizedVerifier
+    )]
+    pub government: Signer<'info>,
+
+    /// CHECK: Any wallet chosen by the government to receive the funds
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let treasury_info = ctx.accounts.treasury.to_account_info();
+
+    // The treasury must stay rent exempt
+    let reserve = Rent::get()?.minimum_balance(Treasury::LEN);
+    let available = treasury_info.lamports().saturating_sub(reserve);
+    require!(amount <= available, FeeError::InsufficientTreasuryFunds);
+
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.total_withdrawn = treasury.total_withdrawn
+        .checked_add(amount)
+        .ok_or(FeeError::FeeOverflow)?;
+
+    emit!(TreasuryWithdrawn {
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("🏛️ {} lamports withdrawn from treasury", amount);
+
+    Ok(())
+}
@@ -6,6 +6,7 @@ pub mod instructions;
 pub mod state;
 pub mod errors;
 pub mod utils;
+pub mod events;
 
 // استيراد الدوال والهياكل
 use instructions::*;
@@ -26,7 +27,9 @@ pub mod car_chain {
         ctx: Context<RegisterUser>,
         user_name: String,
         public_data_uri: String,
+        public_data_digest: DocumentDigest,
         private_data_uri: String,
+        private_data_digest: DocumentDigest,
         encrypted_key_for_gov: String,
         encrypted_key_for_user: String,
         role: UserRoles,
@@ -35,7 +38,9 @@ pub mod car_chain {
             ctx,
             user_name,
             public_data_uri,
+            public_data_digest,
             private_data_uri,
+            private_data_digest,
             encrypted_key_for_gov,
             encrypted_key_for_user,
             role,
@@ -65,6 +70,7 @@ pub mod car_chain {
     inspection_status: InspectionStatus,
     latest_inspection_report: Option<String>,
     mileage: u32,
+    category: VehicleCategory,
     bump: u8,
     ) -> Result<()> {
         instructions::register_car::handler(
@@ -81,6 +87,7 @@ pub mod car_chain {
             inspection_status,
             latest_inspection_report,
             mileage,
+            category,
             bump
            
         )
@@ -98,12 +105,19 @@ pub mod car_chain {
         vin: String) -> Result<()> {
         instructions::cancel_for_sale::handler(ctx, vin)
     }
+    pub fn update_listing_price(
+        ctx: Context<UpdateListingPrice>,
+        vin: String,
+        new_price: u64) -> Result<()> {
+        instructions::update_listing_price::handler(ctx, vin, new_price)
+    }
     
     pub fn request_buy(
         ctx:Context<SendBuyRequest>, 
         vin: String, 
-        message: Option<String>) -> Result<()> {
-        instructions::request_buy::handler(ctx, vin, message)
+        message: Option<String>,
+        acknowledged_accident_count: u16) -> Result<()> {
+        instructions::request_buy::handler(ctx, vin, message, acknowledged_accident_count)
     }
 
     pub fn accept_buy_request(
@@ -121,6 +135,12 @@ pub mod car_chain {
         instructions::reject_buy_request::handler(ctx, vin)
 
     }
+    pub fn reclaim_buy_request(
+        ctx: Context<ReclaimBuyRequest>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::reclaim_buy_request::handler(ctx, vin)
+    }
 
     pub fn transfer_car(
         ctx: Context<TransferCar>,
@@ -130,25 +150,66 @@ pub mod car_chain {
         instructions::transfer_car::handler(ctx,vin,user_name) 
     }
 
+    pub fn initiate_transfer(
+        ctx: Context<InitiateTransfer>,
+        vin: String,
+        recipient: Pubkey,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::initiate_transfer::handler(ctx, vin, recipient, expires_at)
+    }
+
+    pub fn accept_transfer(
+        ctx: Context<AcceptTransfer>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::accept_transfer::handler(ctx, vin)
+    }
+
+    pub fn decline_transfer(ctx: Context<DeclineTransfer>) -> Result<()> {
+        instructions::decline_transfer::handler(ctx)
+    }
+
+    pub fn cancel_transfer(ctx: Context<CancelTransfer>) -> Result<()> {
+        instructions::cancel_transfer::handler(ctx)
+    }
+
+    pub fn force_transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ForceTransfer<'info>>,
+        vin: String,
+        new_owner: Pubkey,
+        reason: ForcedTransferReason,
+        document_uri: String,
+        document_digest: DocumentDigest,
+    ) -> Result<()> {
+        instructions::force_transfer::handler(ctx, vin, new_owner, reason, document_uri, document_digest)
+    }
+
     pub fn issue_car_report(
         ctx: Context<IssueCarReport>,
         report_id: u64,
         vin: String,
+        inspection_type: InspectionType,
+        passed: bool,
         overall_condition: u8,
         engine_condition: u8,
         body_condition: u8,
+        checklist: InspectionChecklist,
         full_report_uri: String,
+        full_report_digest: DocumentDigest,
         report_summary: String,   
         notes: String,
     ) -> Result<()> {
-        instructions::issue_car_report::handler(ctx,report_id,vin,overall_condition,engine_condition,body_condition,full_report_uri,report_summary,notes)
+        instructions::issue_car_report::handler(ctx,report_id,vin,inspection_type,passed,overall_condition,engine_condition,body_condition,checklist,full_report_uri,full_report_digest,report_summary,notes)
     }
 
     pub fn accept_report(
         ctx: Context<AcceptReport>,
         report_id: u64,
+        vin: String,
+        issuer: Pubkey,
     ) -> Result<()> {
-        instructions::accept_report::handler(ctx,report_id)
+        instructions::accept_report::handler(ctx, report_id, vin, issuer)
     }
 
     pub fn issue_confirmity_report(
@@ -158,16 +219,403 @@ pub mod car_chain {
         conformity_status: bool,
         modifications: String,
         full_report_uri: String,
+        full_report_digest: DocumentDigest,
         mines_stamp: String,
         notes: String,
     ) -> Result<()> {
-        instructions::issue_confirmity_report::handler(ctx,report_id,vin,conformity_status,modifications,full_report_uri,mines_stamp,notes)
+        instructions::issue_confirmity_report::handler(ctx,report_id,vin,conformity_status,modifications,full_report_uri,full_report_digest,mines_stamp,notes)
     }
     pub fn accept_confirmity_report(
         ctx: Context<AcceptConfirmityReport>,
         report_id: u64,
+        vin: String,
+        issuer: Pubkey,
     ) -> Result<()> {
-        instructions::accept_confirmity_report::handler(ctx,report_id)
+        instructions::accept_confirmity_report::handler(ctx, report_id, vin, issuer)
+    }
+
+    // 💰 دوال الرسوم والخزينة
+    pub fn set_fee_config(
+        ctx: Context<SetFeeConfig>,
+        sale_fee_bps: u16,
+        registration_fee: u64,
+        transfer_fee: u64,
+    ) -> Result<()> {
+        instructions::set_fee_config::handler(ctx, sale_fee_bps, registration_fee, transfer_fee)
+    }
+
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, amount)
+    }
+
+    pub fn set_tax_config(
+        ctx: Context<SetTaxConfig>,
+        age_brackets: Vec<AgeTaxBracket>,
+        price_brackets: Vec<PriceTaxBracket>,
+        flat_transfer_tax: u64,
+    ) -> Result<()> {
+        instructions::set_tax_config::handler(ctx, age_brackets, price_brackets, flat_transfer_tax)
+    }
+
+    pub fn withdraw_tax_treasury(
+        ctx: Context<WithdrawTaxTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_tax_treasury::handler(ctx, amount)
+    }
+
+    // 🏪 دوال المعارض
+    pub fn register_dealer(
+        ctx: Context<RegisterDealer>,
+        licence_number: String,
+    ) -> Result<()> {
+        instructions::register_dealer::handler(ctx, licence_number)
+    }
+
+    pub fn update_dealer_staff(
+        ctx: Context<UpdateDealerStaff>,
+        staff: Pubkey,
+        add: bool,
+    ) -> Result<()> {
+        instructions::update_dealer_staff::handler(ctx, staff, add)
+    }
+
+    pub fn consign_car(
+        ctx: Context<ConsignCar>,
+        vin: String,
+        commission_bps: u16,
+    ) -> Result<()> {
+        instructions::consign_car::handler(ctx, vin, commission_bps)
+    }
+
+    pub fn end_consignment(
+        ctx: Context<EndConsignment>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::end_consignment::handler(ctx, vin)
+    }
+
+    pub fn batch_set_for_sale<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchSetForSale<'info>>,
+        prices: Vec<u64>,
+    ) -> Result<()> {
+        instructions::batch_set_for_sale::handler(ctx, prices)
+    }
+
+    pub fn batch_cancel_for_sale<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchCancelForSale<'info>>,
+    ) -> Result<()> {
+        instructions::batch_cancel_for_sale::handler(ctx)
+    }
+
+    // 🤝 دوال التفويض
+    pub fn approve_delegate(
+        ctx: Context<ApproveDelegate>,
+        vin: String,
+        delegate: Pubkey,
+        scope: DelegateScope,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::approve_delegate::handler(ctx, vin, delegate, scope, expires_at)
+    }
+
+    pub fn revoke_delegate(
+        ctx: Context<RevokeDelegate>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::revoke_delegate::handler(ctx, vin)
+    }
+
+    // 👥 دوال الملكية المشتركة
+    pub fn enable_co_ownership(
+        ctx: Context<EnableCoOwnership>,
+        vin: String,
+        owners: Vec<CoOwner>,
+        approval_threshold_bps: u16,
+    ) -> Result<()> {
+        instructions::enable_co_ownership::handler(ctx, vin, owners, approval_threshold_bps)
+    }
+
+    pub fn dissolve_co_ownership(
+        ctx: Context<DissolveCoOwnership>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::dissolve_co_ownership::handler(ctx, vin)
+    }
+
+    // 🏢 دوال الشركات والأساطيل
+    pub fn register_organization(
+        ctx: Context<RegisterOrganization>,
+        name: String,
+        registration_number: String,
+    ) -> Result<()> {
+        instructions::register_organization::handler(ctx, name, registration_number)
+    }
+
+    pub fn verify_organization(
+        ctx: Context<VerifyOrganization>,
+        approve: bool,
+    ) -> Result<()> {
+        instructions::verify_organization::handler(ctx, approve)
+    }
+
+    pub fn update_organization_member(
+        ctx: Context<UpdateOrganizationMember>,
+        member: Pubkey,
+        role: Option<OrganizationRole>,
+    ) -> Result<()> {
+        instructions::update_organization_member::handler(ctx, member, role)
+    }
+
+    pub fn withdraw_organization_funds(
+        ctx: Context<WithdrawOrganizationFunds>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_organization_funds::handler(ctx, amount)
+    }
+
+    // 🏦 دوال الرهن والتمويل
+    pub fn register_lien(
+        ctx: Context<RegisterLien>,
+        vin: String,
+        loan_reference: String,
+        principal: u64,
+        outstanding: u64,
+    ) -> Result<()> {
+        instructions::register_lien::handler(ctx, vin, loan_reference, principal, outstanding)
+    }
+
+    pub fn update_lien_payoff(
+        ctx: Context<UpdateLienPayoff>,
+        outstanding: u64,
+    ) -> Result<()> {
+        instructions::update_lien_payoff::handler(ctx, outstanding)
+    }
+
+    pub fn release_lien(
+        ctx: Context<ReleaseLien>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::release_lien::handler(ctx, vin)
+    }
+
+    // 📆 دوال البيع بالتقسيط
+    pub fn propose_installment_plan(
+        ctx: Context<ProposeInstallmentPlan>,
+        vin: String,
+        buyer: Pubkey,
+        terms: InstallmentTerms,
+    ) -> Result<()> {
+        instructions::propose_installment_plan::handler(ctx, vin, buyer, terms)
+    }
+
+    pub fn accept_installment_plan(
+        ctx: Context<AcceptInstallmentPlan>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::accept_installment_plan::handler(ctx, vin)
+    }
+
+    pub fn pay_installment(ctx: Context<PayInstallment>, vin: String) -> Result<()> {
+        instructions::pay_installment::handler(ctx, vin)
+    }
+
+    pub fn complete_installment_plan(
+        ctx: Context<CompleteInstallmentPlan>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::complete_installment_plan::handler(ctx, vin)
+    }
+
+    pub fn claim_installment_default(
+        ctx: Context<ClaimInstallmentDefault>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::claim_installment_default::handler(ctx, vin)
+    }
+
+    pub fn cancel_installment_plan(
+        ctx: Context<CancelInstallmentPlan>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::cancel_installment_plan::handler(ctx, vin)
+    }
+
+    // 🚨 دوال الإبلاغ عن السرقة
+    pub fn report_stolen(
+        ctx: Context<ReportStolen>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::report_stolen::handler(ctx, vin)
+    }
+
+    pub fn recover_vehicle(
+        ctx: Context<RecoverVehicle>,
+        vin: String,
+    ) -> Result<()> {
+        instructions::recover_vehicle::handler(ctx, vin)
+    }
+
+    // 🗑️ دوال إلغاء التسجيل
+    pub fn deregister_car(
+        ctx: Context<DeregisterCar>,
+        vin: String,
+        reason: DeregistrationReason,
+        certificate_uri: String,
+        certificate_digest: DocumentDigest,
+    ) -> Result<()> {
+        instructions::deregister_car::handler(ctx, vin, reason, certificate_uri, certificate_digest)
+    }
+
+    pub fn reregister_car(
+        ctx: Context<ReregisterCar>,
+        vin: String,
+        owner: Pubkey,
+        mileage: u32,
+    ) -> Result<()> {
+        instructions::reregister_car::handler(ctx, vin, owner, mileage)
+    }
+
+    // 💥 دوال سجل الحوادث
+    pub fn record_accident(
+        ctx: Context<RecordAccident>,
+        vin: String,
+        details: AccidentDetails,
+        document_uri: String,
+        document_digest: DocumentDigest,
+    ) -> Result<()> {
+        instructions::record_accident::handler(ctx, vin, details, document_uri, document_digest)
+    }
+
+    pub fn update_accident_repair(
+        ctx: Context<UpdateAccidentRepair>,
+        repair_status: RepairStatus,
+    ) -> Result<()> {
+        instructions::update_accident_repair::handler(ctx, repair_status)
+    }
+
+    // 🔍 دوال صلاحية الفحص الدوري
+    pub fn set_inspection_config(
+        ctx: Context<SetInspectionConfig>,
+        validity_rules: Vec<InspectionValidityRule>,
+        default_validity_seconds: i64,
+        require_valid_inspection_for_sale: bool,
+        max_voided_reports: u32,
+    ) -> Result<()> {
+        instructions::set_inspection_config::handler(
+            ctx,
+            validity_rules,
+            default_validity_seconds,
+            require_valid_inspection_for_sale,
+            max_voided_reports,
+        )
+    }
+
+    pub fn refresh_inspection_status<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefreshInspectionStatus<'info>>,
+    ) -> Result<()> {
+        instructions::refresh_inspection_status::handler(ctx)
+    }
+
+    // 🗓️ دوال حجز مواعيد الفحص
+    pub fn request_inspection(
+        ctx: Context<RequestInspection>,
+        vin: String,
+        requested_type: InspectionType,
+        window_start: i64,
+        window_end: i64,
+        fee: u64,
+    ) -> Result<()> {
+        instructions::request_inspection::handler(ctx, vin, requested_type, window_start, window_end, fee)
+    }
+
+    pub fn claim_inspection(ctx: Context<ClaimInspection>) -> Result<()> {
+        instructions::claim_inspection::handler(ctx)
+    }
+
+    pub fn cancel_inspection_request(ctx: Context<CancelInspectionRequest>) -> Result<()> {
+        instructions::cancel_inspection_request::handler(ctx)
+    }
+
+    // ⚖️ دوال الاعتراض على التقارير
+    pub fn dispute_report(
+        ctx: Context<DisputeReport>,
+        report: Pubkey,
+        reason: String,
+        evidence_uri: String,
+        evidence_digest: DocumentDigest,
+    ) -> Result<()> {
+        instructions::dispute_report::handler(ctx, report, reason, evidence_uri, evidence_digest)
+    }
+
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        resolution: DisputeResolution,
+    ) -> Result<()> {
+        instructions::resolve_dispute::handler(ctx, resolution)
+    }
+
+    // 📝 دوال تعديل وسحب التقارير
+    pub fn amend_report(
+        ctx: Context<AmendReport>,
+        report_id: u64,
+        amendment: ReportAmendment,
+    ) -> Result<()> {
+        instructions::amend_report::handler(ctx, report_id, amendment)
+    }
+
+    pub fn amend_confirmity_report(
+        ctx: Context<AmendConfirmityReport>,
+        report_id: u64,
+        amendment: ConformityAmendment,
+    ) -> Result<()> {
+        instructions::amend_confirmity_report::handler(ctx, report_id, amendment)
+    }
+
+    pub fn revoke_report(ctx: Context<RevokeReport>) -> Result<()> {
+        instructions::revoke_report::handler(ctx)
+    }
+
+    // 📊 سجل أداء المفتشين
+    pub fn reinstate_inspector(ctx: Context<ReinstateInspector>) -> Result<()> {
+        instructions::reinstate_inspector::handler(ctx)
+    }
+
+    // 🏭 مراكز الفحص
+    pub fn register_inspection_center(
+        ctx: Context<RegisterInspectionCenter>,
+        licence_number: String,
+        name: String,
+        address_uri: String,
+        address_digest: DocumentDigest,
+        capacity: u16,
+        approved_inspection_types: Vec<InspectionType>,
+    ) -> Result<()> {
+        instructions::register_inspection_center::handler(
+            ctx,
+            licence_number,
+            name,
+            address_uri,
+            address_digest,
+            capacity,
+            approved_inspection_types,
+        )
+    }
+
+    pub fn update_inspection_center(
+        ctx: Context<UpdateInspectionCenter>,
+        capacity: u16,
+        approved_inspection_types: Vec<InspectionType>,
+        licence_status: CenterLicenceStatus,
+    ) -> Result<()> {
+        instructions::update_inspection_center::handler(ctx, capacity, approved_inspection_types, licence_status)
+    }
+
+    pub fn update_center_inspectors(ctx: Context<UpdateCenterInspectors>, add: bool) -> Result<()> {
+        instructions::update_center_inspectors::handler(ctx, add)
     }
 
     
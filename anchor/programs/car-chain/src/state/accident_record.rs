@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::state::{DocumentDigest, UserRoles};
+
+// One record per reported accident, seeded by the car and its accident index,
+// so the damage history can be walked from 0 to `CarAccount.accident_count`.
+#[account]
+pub struct AccidentRecord {
+    pub car: Pubkey,
+    pub accident_index: u16,
+    pub reported_by: Pubkey,
+    pub reporter_role: UserRoles,        // Inspector, Insurer or LawEnforcement
+    pub accident_date: i64,
+    pub severity: AccidentSeverity,
+    pub damaged_zones: DamagedZones,
+    pub repair_status: RepairStatus,
+    pub document_uri: String,            // police report, claim file or inspection document
+    pub document_digest: DocumentDigest,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl AccidentRecord {
+    pub const MAX_DOCUMENT_URI_LEN: usize = 200;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        2 + // accident_index (u16)
+        32 + // reported_by (Pubkey)
+        1 + // reporter_role (UserRoles enum - 1 byte)
+        8 + // accident_date (i64)
+        1 + // severity (AccidentSeverity enum - 1 byte)
+        DamagedZones::LEN + // damaged_zones (DamagedZones)
+        1 + // repair_status (RepairStatus enum - 1 byte)
+        (4 + Self::MAX_DOCUMENT_URI_LEN) + // document_uri (String)
+        DocumentDigest::LEN + // document_digest
+        8 + // created_at (i64)
+        8 + // updated_at (i64)
+        1; // bump (u8)
+
+    // Who may file accident records: inspectors, insurers and the police
+    pub fn can_report(role: &UserRoles) -> bool {
+        matches!(role, UserRoles::Inspector | UserRoles::Insurer | UserRoles::LawEnforcement)
+    }
+}
+
+// What the reporter states about the accident itself
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct AccidentDetails {
+    pub accident_date: i64,
+    pub severity: AccidentSeverity,
+    pub damaged_zones: DamagedZones,
+    pub repair_status: RepairStatus,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum AccidentSeverity {
+    Minor,
+    Moderate,
+    Severe,
+    TotalLoss,
+}
+
+impl AccidentSeverity {
+    // Severe damage marks the car for good, whatever the repair status
+    pub fn is_severe(&self) -> bool {
+        matches!(self, AccidentSeverity::Severe | AccidentSeverity::TotalLoss)
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum RepairStatus {
+    NotRepaired,
+    InProgress,
+    Repaired,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct DamagedZones {
+    pub front: bool,
+    pub rear: bool,
+    pub left_side: bool,
+    pub right_side: bool,
+    pub roof: bool,
+    pub underbody: bool,
+    pub engine: bool,
+    pub interior: bool,
+}
+
+impl DamagedZones {
+    pub const LEN: usize = 8;
+
+    pub fn any(&self) -> bool {
+        self.front
+            || self.rear
+            || self.left_side
+            || self.right_side
+            || self.roof
+            || self.underbody
+            || self.engine
+            || self.interior
+    }
+}
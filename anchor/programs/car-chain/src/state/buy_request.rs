@@ -10,9 +10,27 @@ pub struct BuyRequest {
     pub status: BuyRequestStatus, // Pending, Approved, Rejected
     pub created_at: i64,
     pub message: Option<String>,
+    pub listing_version: u32, // Listing version the offer was made against
+    pub tax_amount: u64,      // Transfer tax escrowed on top of the amount
+    pub tax_rate_bps: u16,    // Tax rate applied when the offer was made
 }
 
 impl BuyRequest {
+    const MAX_VIN_LEN: usize = 17;
+    const MAX_MESSAGE_LEN: usize = 200;
+
+    pub const LEN: usize = 8 + // discriminator
+        (4 + Self::MAX_VIN_LEN) + // vin (String)
+        32 + // buyer (Pubkey)
+        32 + // seller (Pubkey)
+        8 + // amount (u64)
+        1 + // status (BuyRequestStatus enum - 1 byte)
+        8 + // created_at (i64)
+        (1 + 4 + Self::MAX_MESSAGE_LEN) + // message (Option<String>)
+        4 + // listing_version (u32)
+        8 + // tax_amount (u64)
+        2; // tax_rate_bps (u16)
+
     pub fn initialize(
         &mut self,
         vin: String,
@@ -20,6 +38,9 @@ impl BuyRequest {
         seller: Pubkey,
         amount: u64,
         message: Option<String>,
+        listing_version: u32,
+        tax_amount: u64,
+        tax_rate_bps: u16,
     ) -> Result<()> {
         self.vin = vin;
         self.buyer = buyer;
@@ -28,6 +49,9 @@ impl BuyRequest {
         self.status = BuyRequestStatus::Pending;
         self.created_at = Clock::get()?.unix_timestamp;
         self.message = message;
+        self.listing_version = listing_version;
+        self.tax_amount = tax_amount;
+        self.tax_rate_bps = tax_rate_bps;
         Ok(())
     }
 }
@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use crate::errors::CarError;
+use crate::state::{CarReport, InspectionConfig, InspectionType};
 
 #[account]
 pub struct CarAccount {
@@ -16,10 +18,25 @@ pub struct CarAccount {
     pub transfer_count: u32,
     pub last_inspection_date: Option<i64>,
     pub inspection_status: InspectionStatus,
+    pub inspection_expires_at: Option<i64>,     // set by accepted periodic reports
     pub latest_inspection_report: Option<String>,
     pub mileage: u32,
     pub is_for_sale: bool,
     pub sale_price: Option<u64>,
+    pub listing_version: u32,
+    pub consigned_to: Option<Pubkey>, // DealerAccount selling the car on the owner's behalf
+    pub delegate: Option<CarDelegate>,
+    pub is_co_owned: bool,            // owner acts with co-owners, see CoOwnership
+    pub lienholder: Option<Pubkey>,   // lender wallet while a Lien is active
+    pub installment_plan: Option<Pubkey>, // InstallmentPlan locking the car
+    pub is_stolen: bool,
+    pub stolen_reported_by: Option<Pubkey>,
+    pub stolen_reported_at: Option<i64>,
+    pub registration_count: u16,                // 1 + number of re-registrations
+    pub last_deregistration: Option<Pubkey>,    // DeregistrationRecord while inactive
+    pub accident_count: u16,
+    pub has_severe_damage: bool,                // set by any Severe or TotalLoss AccidentRecord
+    pub category: VehicleCategory,
     pub bump: u8,
 }
 
@@ -48,10 +65,25 @@ impl CarAccount {
         4 + // transfer_count (u32)
         8 + // last_inspection_date (i64)
         1 + // inspection_status (InspectionStatus enum - 1 byte)
+        9 + // inspection_expires_at (Option<i64>)
         (4 + Self::MAX_INSPECTION_REPORT_LEN) + // latest_inspection_report (String)
         4 + // mileage (u32)
         1 + // is_for_sale (bool)
         9 + // sale_price (Option<u64>)
+        4 + // listing_version (u32)
+        33 + // consigned_to (Option<Pubkey>)
+        (1 + CarDelegate::LEN) + // delegate (Option<CarDelegate>)
+        1 + // is_co_owned (bool)
+        33 + // lienholder (Option<Pubkey>)
+        33 + // installment_plan (Option<Pubkey>)
+        1 + // is_stolen (bool)
+        33 + // stolen_reported_by (Option<Pubkey>)
+        9 + // stolen_reported_at (Option<i64>)
+        2 + // registration_count (u16)
+        33 + // last_deregistration (Option<Pubkey>)
+        2 + // accident_count (u16)
+        1 + // has_severe_damage (bool)
+        1 + // category (VehicleCategory enum - 1 byte)
         1; // bump (u8)
 
     pub fn initialize(
@@ -70,6 +102,7 @@ impl CarAccount {
         inspection_status: InspectionStatus,
         latest_inspection_report: Option<String>,
         mileage: u32,
+        category: VehicleCategory,
         bump: u8,
     ) -> Result<()> {
         self.car_id = car_id;
@@ -86,13 +119,104 @@ impl CarAccount {
         self.transfer_count = 0;
         self.last_inspection_date = last_inspection_date;
         self.inspection_status = inspection_status;
+        self.inspection_expires_at = None;
         self.latest_inspection_report = latest_inspection_report;
         self.mileage = mileage;
         self.is_for_sale = false;
         self.sale_price = None;
+        self.listing_version = 0;
+        self.consigned_to = None;
+        self.delegate = None;
+        self.is_co_owned = false;
+        self.lienholder = None;
+        self.installment_plan = None;
+        self.is_stolen = false;
+        self.stolen_reported_by = None;
+        self.stolen_reported_at = None;
+        self.registration_count = 1;
+        self.last_deregistration = None;
+        self.accident_count = 0;
+        self.has_severe_damage = false;
+        self.category = category;
         self.bump = bump;
         Ok(())
     }
+
+    // The owner may do anything; a delegate only what its unexpired scope allows.
+    // Nobody acts alone on a co-owned car.
+    pub fn is_authorized(&self, key: &Pubkey, now: i64, action: DelegateAction) -> bool {
+        if self.is_co_owned {
+            return false;
+        }
+        if self.owner == *key {
+            return true;
+        }
+        match &self.delegate {
+            Some(delegate) => {
+                delegate.delegate == *key && now < delegate.expires_at && delegate.scope.allows(action)
+            }
+            None => false,
+        }
+    }
+
+    // Returns the index of the ownership change being recorded and advances the count
+    pub fn next_transfer_index(&mut self) -> Result<u32> {
+        let transfer_index = self.transfer_count;
+        self.transfer_count = self.transfer_count
+            .checked_add(1)
+            .ok_or(CarError::TransferCountOverflow)?;
+        Ok(transfer_index)
+    }
+
+    pub fn is_inspection_expired(&self, now: i64) -> bool {
+        self.inspection_expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    pub fn has_valid_inspection(&self, now: i64) -> bool {
+        self.inspection_status == InspectionStatus::Passed && !self.is_inspection_expired(now)
+    }
+
+    // An accepted or upheld report becomes the car's inspection record,
+    // unless a more recent report has already been applied
+    pub fn apply_inspection_report(
+        &mut self,
+        report_key: Pubkey,
+        report: &CarReport,
+        inspection_config: &InspectionConfig,
+        now: i64,
+    ) {
+        if self.last_inspection_date.is_some_and(|date| report.report_date < date) {
+            return;
+        }
+
+        self.inspection_status = if report.passed {
+            InspectionStatus::Passed
+        } else {
+            InspectionStatus::Failed
+        };
+        self.last_inspection_date = Some(report.report_date);
+        self.latest_inspection_report = Some(report_key.to_string());
+
+        // Only a periodic inspection restarts the validity period
+        if report.inspection_type == InspectionType::Periodic {
+            self.inspection_expires_at = if report.passed {
+                let vehicle_age = self.age_at(report.report_date);
+                Some(report.report_date.saturating_add(inspection_config.validity_seconds(self.category, vehicle_age)))
+            } else {
+                None
+            };
+        }
+        if self.is_inspection_expired(now) {
+            self.inspection_status = InspectionStatus::Expired;
+        }
+    }
+
+    // Age in whole years of the model year at the given time
+    pub fn age_at(&self, unix_timestamp: i64) -> u16 {
+        const SECONDS_PER_YEAR: i64 = 31_556_952;
+        let current_year = 1970 + unix_timestamp / SECONDS_PER_YEAR;
+        current_year.saturating_sub(self.year as i64).clamp(0, u16::MAX as i64) as u16
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
@@ -101,4 +225,49 @@ pub enum InspectionStatus {
     Passed,
     Failed,
     Expired
-}
\ No newline at end of file
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum VehicleCategory {
+    Private,
+    Commercial,
+    Motorcycle,
+    Heavy,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct DelegateScope {
+    pub list: bool,          // set_for_sale, update_listing_price, cancel_for_sale
+    pub accept_offers: bool, // accept_buy_request, reject_buy_request
+    pub transfer: bool,      // transfer_car
+}
+
+impl DelegateScope {
+    pub fn allows(&self, action: DelegateAction) -> bool {
+        match action {
+            DelegateAction::List => self.list,
+            DelegateAction::AcceptOffers => self.accept_offers,
+            DelegateAction::Transfer => self.transfer,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct CarDelegate {
+    pub delegate: Pubkey,
+    pub scope: DelegateScope,
+    pub expires_at: i64,
+}
+
+impl CarDelegate {
+    pub const LEN: usize = 32 + // delegate (Pubkey)
+        3 + // scope (DelegateScope)
+        8; // expires_at (i64)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum DelegateAction {
+    List,
+    AcceptOffers,
+    Transfer,
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::DocumentDigest;
 
 #[account]
 #[derive(Default)]
@@ -7,20 +8,32 @@ pub struct CarReport {
     pub report_id: u64,
     pub car: Pubkey,
     pub inspector: Pubkey,
+    pub inspection_center: Pubkey, // issuing InspectionCenter
     pub car_owner: Pubkey,
     pub report_date: i64,
+    pub inspection_type: InspectionType,
+    pub passed: bool,           // inspector's verdict, applied to the car once accepted
     
     // Condition ratings
     pub overall_condition: u8, // Scale 1-10
     pub engine_condition: u8,  // Scale 1-10
     pub body_condition: u8,    // Scale 1-10
+
+    // Standard inspection points and measurements
+    pub checklist: InspectionChecklist,
     
     // Report details
     pub full_report_uri: String,
+    pub full_report_digest: DocumentDigest,
     pub report_summary: String,
     
     // Approval status
-    pub approved_by_owner: bool,
+    pub status: ReportStatus,
+
+    // Revisions: an amendment is a new report linked to the one it replaces
+    pub revision: u16,
+    pub previous_revision: Option<Pubkey>,
+    pub superseded_by: Option<Pubkey>,
     
     // Additional info
     pub notes: String,
@@ -30,17 +43,164 @@ pub struct CarReport {
 }
 
 impl CarReport {
-    pub const MAXIMUM_SIZE: usize = 32 + // car pubkey
+    pub const MAX_URI_LENGTH: usize = 256;
+    pub const MAX_SUMMARY_LENGTH: usize = 512;
+
+    // Excluding the discriminator
+    pub const MAXIMUM_SIZE: usize = 8 + // report_id
+        32 + // car pubkey
         32 + // inspector pubkey
+        32 + // inspection_center pubkey
         32 + // car_owner pubkey
         8 +  // report_date
+        1 +  // inspection_type
+        1 +  // passed
         1 +  // overall_condition
         1 +  // engine_condition
         1 +  // body_condition
-        256 + // full_report_uri (String)
-        256 + // report_summary (String)
-        1 +   // approved_by_owner
-        200 + // notes
+        InspectionChecklist::LEN + // checklist
+        (4 + Self::MAX_URI_LENGTH) + // full_report_uri (String)
+        DocumentDigest::LEN + // full_report_digest
+        (4 + Self::MAX_SUMMARY_LENGTH) + // report_summary (String)
+        1 +   // status
+        2 +   // revision
+        33 +  // previous_revision
+        33 +  // superseded_by
+        (4 + Self::MAX_NOTES_LENGTH) + // notes
         1;    // bump
     pub const MAX_NOTES_LENGTH: usize = 200;
+
+    // Reports are seeded by their issuing inspector's wallet, so rederiving
+    // the address proves who issued it
+    pub fn is_issued_by(&self, report_key: &Pubkey, inspector: &Pubkey) -> bool {
+        Pubkey::create_program_address(
+            &[
+                b"car_report",
+                self.car.as_ref(),
+                inspector.as_ref(),
+                &self.report_id.to_le_bytes(),
+                &[self.bump],
+            ],
+            &crate::ID,
+        )
+        .is_ok_and(|address| address == *report_key)
+    }
+}
+
+// Standard inspection points, in their bit order within InspectionChecklist::results
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum ChecklistItem {
+    Brakes,     // الفرامل
+    Tyres,      // الإطارات
+    Lights,     // الأضواء
+    Emissions,  // الانبعاثات
+    Suspension, // نظام التعليق
+    Steering,   // نظام التوجيه
+    Exhaust,    // نظام العادم
+    Bodywork,   // الهيكل
+    SeatBelts,  // أحزمة الأمان
+    Wipers,     // المساحات
+    Mirrors,    // المرايا
+    Horn,       // المنبه
+}
+
+impl ChecklistItem {
+    pub const COUNT: u32 = 12;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum ItemResult {
+    NotChecked,
+    Pass,
+    Advisory, // passes, but needs attention soon
+    Fail,
+}
+
+// Packed so the whole checklist is readable straight from the account:
+// two bits per ChecklistItem in `results`, measurements in fixed units
+// with 0 meaning "not measured"
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default)]
+pub struct InspectionChecklist {
+    pub results: u32,
+    pub tyre_tread_depth: [u8; 4],     // tenths of a mm: front left, front right, rear left, rear right
+    pub brake_efficiency: u8,          // service brake, percent
+    pub parking_brake_efficiency: u8,  // percent
+    pub co_emissions: u16,             // CO, hundredths of a percent by volume
+}
+
+impl InspectionChecklist {
+    pub const LEN: usize = 4 + // results
+        4 + // tyre_tread_depth
+        1 + // brake_efficiency
+        1 + // parking_brake_efficiency
+        2;  // co_emissions
+
+    pub fn result(&self, item: ChecklistItem) -> ItemResult {
+        match (self.results >> (item as u32 * 2)) & 0b11 {
+            0 => ItemResult::NotChecked,
+            1 => ItemResult::Pass,
+            2 => ItemResult::Advisory,
+            _ => ItemResult::Fail,
+        }
+    }
+
+    // One bit per ChecklistItem that failed
+    pub fn failed_items(&self) -> u16 {
+        (0..ChecklistItem::COUNT)
+            .filter(|i| (self.results >> (i * 2)) & 0b11 == ItemResult::Fail as u32)
+            .fold(0, |mask, i| mask | 1 << i)
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.failed_items() != 0
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.results >> (ChecklistItem::COUNT * 2) == 0
+            && self.brake_efficiency <= 100
+            && self.parking_brake_efficiency <= 100
+    }
+}
+
+// Corrected findings for a new revision; the inspection date and type carry over
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct ReportAmendment {
+    pub passed: bool,
+    pub overall_condition: u8,
+    pub engine_condition: u8,
+    pub body_condition: u8,
+    pub checklist: InspectionChecklist,
+    pub full_report_uri: String,
+    pub full_report_digest: DocumentDigest,
+    pub report_summary: String,
+    pub notes: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default, InitSpace)]
+pub enum InspectionType {
+    #[default]
+    Periodic,     // فحص دوري
+    PreSale,      // فحص ما قبل البيع
+    PostAccident, // فحص ما بعد الحادث
+    Custom,       // فحص مخصص
+}
+
+// Shared by CarReport and ConformityReport
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Default)]
+pub enum ReportStatus {
+    #[default]
+    Pending,    // awaiting the owner
+    Accepted,   // accepted by the owner
+    Disputed,   // owner raised a ReportDispute, awaiting the government
+    Upheld,     // dispute rejected, the report stands
+    Voided,     // dispute accepted, the report is void
+    Superseded, // replaced by an amended revision
+    Revoked,    // withdrawn by the issuer or the government
+}
+
+impl ReportStatus {
+    // Reports that still say something about the car
+    pub fn is_live(&self) -> bool {
+        matches!(self, ReportStatus::Pending | ReportStatus::Accepted | ReportStatus::Upheld)
+    }
 }
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct CoOwnership {
+    pub car: Pubkey,
+    pub owners: Vec<CoOwner>,
+    pub approval_threshold_bps: u16, // share of ownership that must sign sales and transfers
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct CoOwner {
+    pub owner: Pubkey,
+    pub share_bps: u16,
+}
+
+impl CoOwnership {
+    pub const MAX_OWNERS: usize = 5;
+    pub const TOTAL_BPS: u16 = 10_000;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        (4 + Self::MAX_OWNERS * (32 + 2)) + // owners (Vec<CoOwner>)
+        2 + // approval_threshold_bps (u16)
+        8 + // created_at (i64)
+        1; // bump (u8)
+
+    // Co-owners count towards the threshold if they are the authority or sign
+    // the transaction as one of the remaining accounts
+    pub fn approves(&self, authority: &Pubkey, remaining_accounts: &[AccountInfo]) -> bool {
+        let signed_bps: u32 = self.owners
+            .iter()
+            .filter(|co_owner| {
+                co_owner.owner == *authority
+                    || remaining_accounts
+                        .iter()
+                        .any(|account| account.is_signer && account.key == &co_owner.owner)
+            })
+            .map(|co_owner| co_owner.share_bps as u32)
+            .sum();
+        signed_bps >= self.approval_threshold_bps as u32
+    }
+
+    // Splits an amount by share; rounding dust goes to the last co-owner
+    pub fn split(&self, amount: u64) -> Vec<(Pubkey, u64)> {
+        let mut remaining = amount;
+        let last = self.owners.len().saturating_sub(1);
+        self.owners
+            .iter()
+            .enumerate()
+            .map(|(index, co_owner)| {
+                let share = if index == last {
+                    remaining
+                } else {
+                    ((amount as u128 * co_owner.share_bps as u128) / Self::TOTAL_BPS as u128) as u64
+                };
+                remaining -= share;
+                (co_owner.owner, share)
+            })
+            .collect()
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::{DocumentDigest, ReportStatus};
 
 #[account]
 pub struct ConformityReport {
@@ -11,7 +12,11 @@ pub struct ConformityReport {
     pub modifications: String,
     pub mines_stamp: String,
     pub full_report_uri: String,
-    pub accepted_by_owner: bool,
+    pub full_report_digest: DocumentDigest,
+    pub status: ReportStatus,
+    pub revision: u16,
+    pub previous_revision: Option<Pubkey>,
+    pub superseded_by: Option<Pubkey>,
     pub notes: String,
     pub bump: u8,
 }
@@ -28,7 +33,37 @@ impl ConformityReport {
         4 + 256 + // modifications (string prefix + max bytes)
         4 + 256 + // mines_stamp (string prefix + max bytes)
         4 + 256 + // full_report_uri (string prefix + max bytes)
-        1 +  // accepted_by_owner
+        DocumentDigest::LEN + // full_report_digest
+        1 +  // status
+        2 +  // revision
+        33 + // previous_revision
+        33 + // superseded_by
         4 + 512 + // notes (string prefix + max bytes)
         1;   // bump
+
+    // See CarReport::is_issued_by
+    pub fn is_issued_by(&self, report_key: &Pubkey, confirmity_expert: &Pubkey) -> bool {
+        Pubkey::create_program_address(
+            &[
+                b"conformity_report",
+                self.car.as_ref(),
+                confirmity_expert.as_ref(),
+                &self.report_id.to_le_bytes(),
+                &[self.bump],
+            ],
+            &crate::ID,
+        )
+        .is_ok_and(|address| address == *report_key)
+    }
+}
+
+// Corrected findings for a new revision; the report date carries over
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+pub struct ConformityAmendment {
+    pub conformity_status: bool,
+    pub modifications: String,
+    pub full_report_uri: String,
+    pub full_report_digest: DocumentDigest,
+    pub mines_stamp: String,
+    pub notes: String,
 }
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct DealerAccount {
+    pub authority: Pubkey,         // محفظة المعرض
+    pub user_account: Pubkey,      // UserAccount with the Dealer role
+    pub licence_number: String,
+    pub staff: Vec<Pubkey>,        // wallets allowed to act for the dealer
+    pub active_consignments: u32,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl DealerAccount {
+    pub const MAX_LICENCE_LEN: usize = 50;
+    pub const MAX_STAFF: usize = 10;
+    pub const MAX_BATCH_SIZE: usize = 10;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority (Pubkey)
+        32 + // user_account (Pubkey)
+        (4 + Self::MAX_LICENCE_LEN) + // licence_number (String)
+        (4 + Self::MAX_STAFF * 32) + // staff (Vec<Pubkey>)
+        4 + // active_consignments (u32)
+        8 + // created_at (i64)
+        1; // bump (u8)
+
+    pub fn is_authorized(&self, key: &Pubkey) -> bool {
+        self.authority == *key || self.staff.contains(key)
+    }
+}
+
+#[account]
+pub struct Consignment {
+    pub car: Pubkey,
+    pub owner: Pubkey,
+    pub dealer: Pubkey,         // DealerAccount
+    pub commission_bps: u16,    // dealer share of the seller's proceeds
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Consignment {
+    pub const MAX_BPS: u16 = 10_000;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        32 + // owner (Pubkey)
+        32 + // dealer (Pubkey)
+        2 + // commission_bps (u16)
+        8 + // created_at (i64)
+        1; // bump (u8)
+
+    pub fn commission(&self, proceeds: u64) -> u64 {
+        ((proceeds as u128 * self.commission_bps as u128) / Self::MAX_BPS as u128) as u64
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::DocumentDigest;
+
+// One record per deregistration, seeded by the car and the registration it
+// ended, so a re-imported car keeps a single history across registrations.
+#[account]
+pub struct DeregistrationRecord {
+    pub car: Pubkey,
+    pub vin: String,
+    pub registration_number: u16,        // CarAccount.registration_count at deregistration
+    pub reason: DeregistrationReason,
+    pub certificate_uri: String,         // scrapping / export / destruction certificate
+    pub certificate_digest: DocumentDigest,
+    pub owner: Pubkey,
+    pub deregistered_by: Pubkey,
+    pub deregistered_at: i64,
+    pub reregistered_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl DeregistrationRecord {
+    const MAX_VIN_LEN: usize = 17;
+    pub const MAX_CERTIFICATE_URI_LEN: usize = 200;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        (4 + Self::MAX_VIN_LEN) + // vin (String)
+        2 + // registration_number (u16)
+        1 + // reason (DeregistrationReason enum - 1 byte)
+        (4 + Self::MAX_CERTIFICATE_URI_LEN) + // certificate_uri (String)
+        DocumentDigest::LEN + // certificate_digest
+        32 + // owner (Pubkey)
+        32 + // deregistered_by (Pubkey)
+        8 + // deregistered_at (i64)
+        9 + // reregistered_at (Option<i64>)
+        1; // bump (u8)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum DeregistrationReason {
+    Scrapped,
+    Exported,
+    Destroyed,
+}
+
+impl DeregistrationReason {
+    // Only an exported vehicle still exists to be brought back
+    pub fn allows_reregistration(&self) -> bool {
+        *self == DeregistrationReason::Exported
+    }
+}
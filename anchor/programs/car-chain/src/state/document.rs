@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+// Content digest of an off-chain document, stored next to its URI so the
+// document cannot be swapped after the fact. Checked off-chain by the
+// verifier crate.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Default)]
+pub struct DocumentDigest {
+    pub sha256: [u8; 32],
+    pub media_type: String, // e.g. "application/pdf"
+}
+
+impl DocumentDigest {
+    pub const MAX_MEDIA_TYPE_LEN: usize = 64;
+
+    pub const LEN: usize = 32 + // sha256
+        (4 + Self::MAX_MEDIA_TYPE_LEN); // media_type (String)
+
+    pub fn is_valid(&self) -> bool {
+        self.sha256 != [0; 32]
+            && self.media_type.len() <= Self::MAX_MEDIA_TYPE_LEN
+            && self
+                .media_type
+                .split_once('/')
+                .is_some_and(|(kind, subtype)| !kind.is_empty() && !subtype.is_empty())
+    }
+}
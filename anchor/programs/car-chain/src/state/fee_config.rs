@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::errors::FeeError;
+
+#[account]
+pub struct FeeConfig {
+    pub sale_fee_bps: u16,       // platform fee on sale price, in basis points
+    pub registration_fee: u64,   // flat fee in lamports on register_car
+    pub transfer_fee: u64,       // flat fee in lamports on transfer_car
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl FeeConfig {
+    pub const MAX_BPS: u16 = 10_000;
+
+    pub const LEN: usize = 8 + // discriminator
+        2 + // sale_fee_bps (u16)
+        8 + // registration_fee (u64)
+        8 + // transfer_fee (u64)
+        8 + // updated_at (i64)
+        1; // bump (u8)
+
+    // Platform share of a sale, rounded down
+    pub fn sale_fee(&self, price: u64) -> u64 {
+        ((price as u128 * self.sale_fee_bps as u128) / Self::MAX_BPS as u128) as u64
+    }
+}
+
+#[account]
+pub struct Treasury {
+    pub total_collected: u64,
+    pub total_withdrawn: u64,
+    pub bump: u8,
+}
+
+impl Treasury {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // total_collected (u64)
+        8 + // total_withdrawn (u64)
+        1; // bump (u8)
+
+    pub fn record_fee(&mut self, amount: u64) -> Result<()> {
+        self.total_collected = self.total_collected
+            .checked_add(amount)
+            .ok_or(FeeError::FeeOverflow)?;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum FeeKind {
+    Sale,
+    Registration,
+    Transfer,
+}
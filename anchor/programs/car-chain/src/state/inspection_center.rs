@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{DocumentDigest, InspectionType};
+
+// A government-licensed inspection centre, seeded by its licence number.
+// Car reports can only be issued through an active centre the inspector
+// belongs to.
+#[account]
+pub struct InspectionCenter {
+    pub authority: Pubkey,                          // operator wallet, manages the inspector list
+    pub licence_number: String,
+    pub name: String,
+    pub address_uri: String,
+    pub address_digest: DocumentDigest,
+    pub capacity: u16,                              // inspections per day
+    pub approved_inspection_types: Vec<InspectionType>,
+    pub licence_status: CenterLicenceStatus,
+    pub inspectors: Vec<Pubkey>,                    // UserAccounts of member inspectors
+    pub registered_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl InspectionCenter {
+    pub const MAX_LICENCE_LEN: usize = 50;
+    pub const MAX_NAME_LEN: usize = 100;
+    pub const MAX_ADDRESS_URI_LEN: usize = 200;
+    pub const MAX_INSPECTION_TYPES: usize = 4;
+    pub const MAX_INSPECTORS: usize = 20;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority (Pubkey)
+        (4 + Self::MAX_LICENCE_LEN) + // licence_number (String)
+        (4 + Self::MAX_NAME_LEN) + // name (String)
+        (4 + Self::MAX_ADDRESS_URI_LEN) + // address_uri (String)
+        DocumentDigest::LEN + // address_digest
+        2 + // capacity (u16)
+        (4 + Self::MAX_INSPECTION_TYPES) + // approved_inspection_types (Vec<InspectionType>)
+        1 + // licence_status (CenterLicenceStatus enum - 1 byte)
+        (4 + Self::MAX_INSPECTORS * 32) + // inspectors (Vec<Pubkey>)
+        8 + // registered_at (i64)
+        8 + // updated_at (i64)
+        1; // bump (u8)
+
+    pub fn is_active(&self) -> bool {
+        self.licence_status == CenterLicenceStatus::Active
+    }
+
+    pub fn has_inspector(&self, inspector: &Pubkey) -> bool {
+        self.inspectors.contains(inspector)
+    }
+
+    pub fn approves(&self, inspection_type: InspectionType) -> bool {
+        self.approved_inspection_types.contains(&inspection_type)
+    }
+
+    // At least one type, each listed once
+    pub fn valid_inspection_types(types: &[InspectionType]) -> bool {
+        !types.is_empty()
+            && types.len() <= Self::MAX_INSPECTION_TYPES
+            && types.iter().enumerate().all(|(i, t)| !types[..i].contains(t))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum CenterLicenceStatus {
+    Active,
+    Suspended,
+}
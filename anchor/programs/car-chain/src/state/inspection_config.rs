@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::VehicleCategory;
+
+// How long a passed periodic inspection stays valid, set by the government
+#[account]
+pub struct InspectionConfig {
+    pub authority: Pubkey,
+    pub validity_rules: Vec<InspectionValidityRule>, // sorted by max_age ascending within each category
+    pub default_validity_seconds: i64,               // for categories without rules
+    pub require_valid_inspection_for_sale: bool,
+    pub max_voided_reports: u32,                     // voided reports before an inspector is suspended, 0 = never
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct InspectionValidityRule {
+    pub category: VehicleCategory,
+    pub max_age: u16,           // vehicle age in years, inclusive
+    pub validity_seconds: i64,
+}
+
+impl InspectionConfig {
+    pub const MAX_RULES: usize = 16;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // authority (Pubkey)
+        (4 + Self::MAX_RULES * (1 + 2 + 8)) + // validity_rules (Vec<InspectionValidityRule>)
+        8 + // default_validity_seconds (i64)
+        1 + // require_valid_inspection_for_sale (bool)
+        4 + // max_voided_reports (u32)
+        8 + // updated_at (i64)
+        1; // bump (u8)
+
+    // Cars older than the last rule for their category use that rule's period
+    pub fn validity_seconds(&self, category: VehicleCategory, vehicle_age: u16) -> i64 {
+        let mut rules = self.validity_rules
+            .iter()
+            .filter(|rule| rule.category == category);
+        rules
+            .clone()
+            .find(|rule| vehicle_age <= rule.max_age)
+            .or_else(|| rules.next_back())
+            .map_or(self.default_validity_seconds, |rule| rule.validity_seconds)
+    }
+}
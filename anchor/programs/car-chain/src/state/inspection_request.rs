@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::InspectionType;
+
+// An owner's booking for an inspection, one open at a time per car. The fee
+// is escrowed here and paid to the inspector when the report is issued.
+#[account]
+pub struct InspectionRequest {
+    pub car: Pubkey,
+    pub owner: Pubkey,
+    pub requested_type: InspectionType,
+    pub window_start: i64,              // preferred window for the inspection
+    pub window_end: i64,
+    pub fee: u64,                       // lamports held by this account
+    pub inspector: Option<Pubkey>,      // inspector wallet, once claimed
+    pub status: InspectionRequestStatus,
+    pub created_at: i64,
+    pub claimed_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl InspectionRequest {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        32 + // owner (Pubkey)
+        1 + // requested_type (InspectionType enum - 1 byte)
+        8 + // window_start (i64)
+        8 + // window_end (i64)
+        8 + // fee (u64)
+        33 + // inspector (Option<Pubkey>)
+        1 + // status (InspectionRequestStatus enum - 1 byte)
+        8 + // created_at (i64)
+        9 + // claimed_at (Option<i64>)
+        1; // bump (u8)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum InspectionRequestStatus {
+    Open,
+    Claimed,
+}
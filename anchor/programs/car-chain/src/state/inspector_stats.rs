@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+// An inspector's track record, seeded by the inspector's UserAccount and
+// created with their first claimed booking or report. Inspectors whose voided reports reach
+// InspectionConfig.max_voided_reports are suspended until the government
+// reinstates them.
+#[account]
+pub struct InspectorStats {
+    pub inspector: Pubkey,           // UserAccount of the inspector
+    pub reports_issued: u32,
+    pub reports_accepted: u32,       // accepted by the owner or upheld on dispute
+    pub reports_disputed: u32,       // disputes resolved by the government
+    pub reports_voided: u32,
+    pub last_activity_at: i64,
+    pub suspended_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl InspectorStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // inspector (Pubkey)
+        4 + // reports_issued (u32)
+        4 + // reports_accepted (u32)
+        4 + // reports_disputed (u32)
+        4 + // reports_voided (u32)
+        8 + // last_activity_at (i64)
+        9 + // suspended_at (Option<i64>)
+        1; // bump (u8)
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+
+    // Records a government dispute resolution. Returns true when the
+    // inspector has just been suspended; `max_voided_reports` 0 disables it.
+    pub fn record_dispute(&mut self, voided: bool, max_voided_reports: u32, now: i64) -> bool {
+        self.reports_disputed = self.reports_disputed.saturating_add(1);
+        self.last_activity_at = now;
+        if !voided {
+            self.reports_accepted = self.reports_accepted.saturating_add(1);
+            return false;
+        }
+
+        self.reports_voided = self.reports_voided.saturating_add(1);
+        if max_voided_reports > 0 && self.reports_voided >= max_voided_reports && !self.is_suspended() {
+            self.suspended_at = Some(now);
+            return true;
+        }
+        false
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+// A sale paid over time. Every payment is escrowed in this account; the seller
+// keeps the title until the final instalment, see `complete_installment_plan`.
+#[account]
+pub struct InstallmentPlan {
+    pub car: Pubkey,
+    pub vin: String,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub total_price: u64,
+    pub down_payment: u64,
+    pub installment_amount: u64,
+    pub installment_count: u16,
+    pub installments_paid: u16,
+    pub interval_seconds: i64,
+    pub grace_period_seconds: i64,
+    pub next_due_at: i64,
+    pub amount_paid: u64,
+    pub default_refund_bps: u16,   // share of amount_paid returned to the buyer on default
+    pub status: InstallmentStatus,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+// Terms the seller proposes; the total price is derived from them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct InstallmentTerms {
+    pub down_payment: u64,
+    pub installment_amount: u64,
+    pub installment_count: u16,
+    pub interval_seconds: i64,
+    pub grace_period_seconds: i64,
+    pub default_refund_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum InstallmentStatus {
+    Proposed, // waiting for the buyer's down payment
+    Active,
+}
+
+impl InstallmentPlan {
+    const MAX_VIN_LEN: usize = 17;
+    pub const MAX_BPS: u16 = 10_000;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        (4 + Self::MAX_VIN_LEN) + // vin (String)
+        32 + // seller (Pubkey)
+        32 + // buyer (Pubkey)
+        8 + // total_price (u64)
+        8 + // down_payment (u64)
+        8 + // installment_amount (u64)
+        2 + // installment_count (u16)
+        2 + // installments_paid (u16)
+        8 + // interval_seconds (i64)
+        8 + // grace_period_seconds (i64)
+        8 + // next_due_at (i64)
+        8 + // amount_paid (u64)
+        2 + // default_refund_bps (u16)
+        1 + // status (InstallmentStatus enum - 1 byte)
+        8 + // created_at (i64)
+        1; // bump (u8)
+
+    pub fn is_fully_paid(&self) -> bool {
+        self.installments_paid == self.installment_count
+    }
+
+    pub fn is_in_default(&self, now: i64) -> bool {
+        self.status == InstallmentStatus::Active
+            && !self.is_fully_paid()
+            && now > self.next_due_at.saturating_add(self.grace_period_seconds)
+    }
+
+    pub fn default_refund(&self) -> u64 {
+        ((self.amount_paid as u128 * self.default_refund_bps as u128) / Self::MAX_BPS as u128) as u64
+    }
+}
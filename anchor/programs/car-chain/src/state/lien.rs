@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+// A car pledged as collateral; while it exists the car can only change hands
+// through a sale that pays `outstanding` to the lender first
+#[account]
+pub struct Lien {
+    pub car: Pubkey,
+    pub lender: Pubkey,           // lender wallet, receives the payoff
+    pub owner_at_registration: Pubkey,
+    pub loan_reference: String,
+    pub principal: u64,
+    pub outstanding: u64,         // payoff amount in lamports
+    pub registered_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Lien {
+    pub const MAX_LOAN_REFERENCE_LEN: usize = 64;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        32 + // lender (Pubkey)
+        32 + // owner_at_registration (Pubkey)
+        (4 + Self::MAX_LOAN_REFERENCE_LEN) + // loan_reference (String)
+        8 + // principal (u64)
+        8 + // outstanding (u64)
+        8 + // registered_at (i64)
+        8 + // updated_at (i64)
+        1; // bump (u8)
+}
@@ -3,9 +3,43 @@ pub mod car;
 pub mod buy_request;
 pub mod car_report;
 pub mod confirmity_report;
+pub mod fee_config;
+pub mod tax_config;
+pub mod dealer;
+pub mod pending_transfer;
+pub mod ownership_record;
+pub mod co_ownership;
+pub mod organization;
+pub mod lien;
+pub mod installment_plan;
+pub mod deregistration;
+pub mod accident_record;
+pub mod inspection_config;
+pub mod inspection_request;
+pub mod report_dispute;
+pub mod document;
+pub mod inspector_stats;
+pub mod inspection_center;
 
 pub use user::*;
 pub use car::*;
 pub use buy_request::*;
 pub use car_report::*;
 pub use confirmity_report::*;
+pub use fee_config::*;
+pub use tax_config::*;
+pub use dealer::*;
+pub use pending_transfer::*;
+pub use ownership_record::*;
+pub use co_ownership::*;
+pub use organization::*;
+pub use lien::*;
+pub use installment_plan::*;
+pub use deregistration::*;
+pub use accident_record::*;
+pub use inspection_config::*;
+pub use inspection_request::*;
+pub use report_dispute::*;
+pub use document::*;
+pub use inspector_stats::*;
+pub use inspection_center::*;
\ No newline at end of file
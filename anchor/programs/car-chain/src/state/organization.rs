@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::VerificationStatus;
+
+// A company or fleet operator; cars it owns have the organization PDA as `owner`
+#[account]
+pub struct Organization {
+    pub name: String,
+    pub registration_number: String,   // السجل التجاري
+    pub members: Vec<OrganizationMember>,
+    pub verification_status: VerificationStatus,
+    pub verified_at: Option<i64>,
+    pub created_by: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct OrganizationMember {
+    pub key: Pubkey,
+    pub role: OrganizationRole,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum OrganizationRole {
+    Admin,        // manages members and funds, and the fleet
+    FleetManager, // lists, sells and transfers the organization's cars
+}
+
+impl Organization {
+    pub const MAX_NAME_LEN: usize = 50;
+    pub const MAX_REGISTRATION_NUMBER_LEN: usize = 50;
+    pub const MAX_MEMBERS: usize = 20;
+
+    pub const LEN: usize = 8 + // discriminator
+        (4 + Self::MAX_NAME_LEN) + // name (String)
+        (4 + Self::MAX_REGISTRATION_NUMBER_LEN) + // registration_number (String)
+        (4 + Self::MAX_MEMBERS * (32 + 1)) + // members (Vec<OrganizationMember>)
+        1 + // verification_status (VerificationStatus enum - 1 byte)
+        9 + // verified_at (Option<i64>)
+        32 + // created_by (Pubkey)
+        8 + // created_at (i64)
+        1; // bump (u8)
+
+    pub fn role_of(&self, key: &Pubkey) -> Option<OrganizationRole> {
+        self.members
+            .iter()
+            .find(|member| member.key == *key)
+            .map(|member| member.role)
+    }
+
+    // Any member of a verified organization may act on its cars
+    pub fn can_manage_fleet(&self, key: &Pubkey) -> bool {
+        self.verification_status == VerificationStatus::Verified && self.role_of(key).is_some()
+    }
+
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        self.role_of(key) == Some(OrganizationRole::Admin)
+    }
+}
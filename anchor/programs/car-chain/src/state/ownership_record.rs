@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::DocumentDigest;
+
+// One record per ownership change, seeded by the car and its transfer index,
+// so the provenance chain can be walked from 0 to `CarAccount.transfer_count`.
+#[account]
+pub struct OwnershipRecord {
+    pub car: Pubkey,
+    pub transfer_index: u32,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+    pub timestamp: i64,
+    pub price: Option<u64>,              // sale price, None for transfers without a sale
+    pub mechanism: TransferMechanism,
+    pub bump: u8,
+}
+
+impl OwnershipRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        4 + // transfer_index (u32)
+        32 + // previous_owner (Pubkey)
+        32 + // new_owner (Pubkey)
+        8 + // timestamp (i64)
+        9 + // price (Option<u64>)
+        1 + // mechanism (TransferMechanism enum - 1 byte)
+        1; // bump (u8)
+
+    pub fn initialize(
+        &mut self,
+        car: Pubkey,
+        transfer_index: u32,
+        previous_owner: Pubkey,
+        new_owner: Pubkey,
+        price: Option<u64>,
+        mechanism: TransferMechanism,
+        bump: u8,
+    ) -> Result<()> {
+        self.car = car;
+        self.transfer_index = transfer_index;
+        self.previous_owner = previous_owner;
+        self.new_owner = new_owner;
+        self.timestamp = Clock::get()?.unix_timestamp;
+        self.price = price;
+        self.mechanism = mechanism;
+        self.bump = bump;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum TransferMechanism {
+    Sale,           // accept_buy_request
+    DirectTransfer, // transfer_car
+    Gift,           // initiate_transfer / accept_transfer
+    Forced,         // force_transfer by government, see ForcedTransferRecord
+    Installment,    // complete_installment_plan
+    Reimport,       // reregister_car, see DeregistrationRecord
+}
+
+// Legal basis for a government-forced transfer, stored next to the
+// OwnershipRecord with the same transfer index
+#[account]
+pub struct ForcedTransferRecord {
+    pub car: Pubkey,
+    pub transfer_index: u32,
+    pub reason: ForcedTransferReason,
+    pub document_uri: String,
+    pub document_digest: DocumentDigest,
+    pub ordered_by: Pubkey,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+impl ForcedTransferRecord {
+    pub const MAX_DOCUMENT_URI_LEN: usize = 200;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        4 + // transfer_index (u32)
+        1 + // reason (ForcedTransferReason enum - 1 byte)
+        (4 + Self::MAX_DOCUMENT_URI_LEN) + // document_uri (String)
+        DocumentDigest::LEN + // document_digest
+        32 + // ordered_by (Pubkey)
+        8 + // timestamp (i64)
+        1; // bump (u8)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum ForcedTransferReason {
+    Inheritance,  // وراثة
+    CourtOrder,   // حكم قضائي
+    Seizure,      // حجز
+    Other,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct PendingTransfer {
+    pub car: Pubkey,
+    pub vin: String,
+    pub initiator: Pubkey,   // owner or delegate who started the transfer
+    pub previous_owner: Pubkey,
+    pub recipient: Pubkey,
+    pub fee_amount: u64,     // transfer fee escrowed by the initiator
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl PendingTransfer {
+    const MAX_VIN_LEN: usize = 17;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // car (Pubkey)
+        (4 + Self::MAX_VIN_LEN) + // vin (String)
+        32 + // initiator (Pubkey)
+        32 + // previous_owner (Pubkey)
+        32 + // recipient (Pubkey)
+        8 + // fee_amount (u64)
+        8 + // created_at (i64)
+        8 + // expires_at (i64)
+        1; // bump (u8)
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::DocumentDigest;
+
+// An owner's dispute of a CarReport or ConformityReport, seeded by the report
+// and resolved by the government.
+#[account]
+pub struct ReportDispute {
+    pub report: Pubkey,
+    pub car: Pubkey,
+    pub disputed_by: Pubkey,
+    pub reason: String,
+    pub evidence_uri: String,
+    pub evidence_digest: DocumentDigest,
+    pub created_at: i64,
+    pub resolution: Option<DisputeResolution>,
+    pub resolved_by: Option<Pubkey>,
+    pub resolved_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl ReportDispute {
+    pub const MAX_REASON_LEN: usize = 200;
+    pub const MAX_EVIDENCE_URI_LEN: usize = 200;
+
+    pub const LEN: usize = 8 + // discriminator
+        32 + // report (Pubkey)
+        32 + // car (Pubkey)
+        32 + // disputed_by (Pubkey)
+        (4 + Self::MAX_REASON_LEN) + // reason (String)
+        (4 + Self::MAX_EVIDENCE_URI_LEN) + // evidence_uri (String)
+        DocumentDigest::LEN + // evidence_digest
+        8 + // created_at (i64)
+        2 + // resolution (Option<DisputeResolution>)
+        33 + // resolved_by (Option<Pubkey>)
+        9 + // resolved_at (Option<i64>)
+        1; // bump (u8)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub enum DisputeResolution {
+    Upheld, // the report stands
+    Voided, // the report is void
+}
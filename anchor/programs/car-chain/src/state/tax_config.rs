@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use crate::errors::TaxError;
+
+#[account]
+pub struct TaxConfig {
+    pub age_brackets: Vec<AgeTaxBracket>,     // sorted by max_age ascending
+    pub price_brackets: Vec<PriceTaxBracket>, // sorted by min_price ascending
+    pub flat_transfer_tax: u64,               // lamports, for transfers without a sale price
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct AgeTaxBracket {
+    pub max_age: u16,   // vehicle age in years, inclusive
+    pub rate_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq)]
+pub struct PriceTaxBracket {
+    pub min_price: u64, // lamports, inclusive
+    pub surcharge_bps: u16,
+}
+
+impl TaxConfig {
+    pub const MAX_BRACKETS: usize = 8;
+    pub const MAX_BPS: u16 = 10_000;
+
+    pub const LEN: usize = 8 + // discriminator
+        (4 + Self::MAX_BRACKETS * (2 + 2)) + // age_brackets (Vec<AgeTaxBracket>)
+        (4 + Self::MAX_BRACKETS * (8 + 2)) + // price_brackets (Vec<PriceTaxBracket>)
+        8 + // flat_transfer_tax (u64)
+        8 + // updated_at (i64)
+        1; // bump (u8)
+
+    // Age bracket rate plus the highest price surcharge that applies.
+    // Cars older than the last age bracket use the last bracket's rate.
+    pub fn rate_bps(&self, vehicle_age: u16, price: u64) -> u16 {
+        let age_rate = self.age_brackets
+            .iter()
+            .find(|bracket| vehicle_age <= bracket.max_age)
+            .or(self.age_brackets.last())
+            .map_or(0, |bracket| bracket.rate_bps);
+        let price_rate = self.price_brackets
+            .iter()
+            .filter(|bracket| price >= bracket.min_price)
+            .map(|bracket| bracket.surcharge_bps)
+            .max()
+            .unwrap_or(0);
+        age_rate.saturating_add(price_rate).min(Self::MAX_BPS)
+    }
+
+    pub fn sale_tax(&self, vehicle_age: u16, price: u64) -> u64 {
+        let rate_bps = self.rate_bps(vehicle_age, price);
+        ((price as u128 * rate_bps as u128) / Self::MAX_BPS as u128) as u64
+    }
+}
+
+// Transfer tax is kept apart from platform fees, so fee withdrawals can
+// never reach it
+#[account]
+pub struct TaxTreasury {
+    pub total_collected: u64,
+    pub total_withdrawn: u64,
+    pub bump: u8,
+}
+
+impl TaxTreasury {
+    pub const LEN: usize = 8 + // discriminator
+        8 + // total_collected (u64)
+        8 + // total_withdrawn (u64)
+        1; // bump (u8)
+
+    pub fn record_tax(&mut self, amount: u64) -> Result<()> {
+        self.total_collected = self.total_collected
+            .checked_add(amount)
+            .ok_or(TaxError::TaxOverflow)?;
+        Ok(())
+    }
+}
+
+// One receipt per taxed transfer, seeded by the car and the transfer index
+// it shares with the OwnershipRecord
+#[account]
+pub struct TaxReceipt {
+    pub transfer_index: u32,
+    pub car: Pubkey,
+    pub vin: String,
+    pub payer: Pubkey,            // new owner who paid the tax
+    pub previous_owner: Pubkey,
+    pub sale_price: Option<u64>,  // None for transfers without a sale
+    pub vehicle_age: u16,
+    pub rate_bps: u16,
+    pub amount: u64,
+    pub issued_at: i64,
+    pub bump: u8,
+}
+
+impl TaxReceipt {
+    const MAX_VIN_LEN: usize = 17;
+
+    pub const LEN: usize = 8 + // discriminator
+        4 + // transfer_index (u32)
+        32 + // car (Pubkey)
+        (4 + Self::MAX_VIN_LEN) + // vin (String)
+        32 + // payer (Pubkey)
+        32 + // previous_owner (Pubkey)
+        9 + // sale_price (Option<u64>)
+        2 + // vehicle_age (u16)
+        2 + // rate_bps (u16)
+        8 + // amount (u64)
+        8 + // issued_at (i64)
+        1; // bump (u8)
+
+    pub fn initialize(
+        &mut self,
+        transfer_index: u32,
+        car: Pubkey,
+        vin: String,
+        payer: Pubkey,
+        previous_owner: Pubkey,
+        sale_price: Option<u64>,
+        vehicle_age: u16,
+        rate_bps: u16,
+        amount: u64,
+        bump: u8,
+    ) -> Result<()> {
+        self.transfer_index = transfer_index;
+        self.car = car;
+        self.vin = vin;
+        self.payer = payer;
+        self.previous_owner = previous_owner;
+        self.sale_price = sale_price;
+        self.vehicle_age = vehicle_age;
+        self.rate_bps = rate_bps;
+        self.amount = amount;
+        self.issued_at = Clock::get()?.unix_timestamp;
+        self.bump = bump;
+        Ok(())
+    }
+}
@@ -1,11 +1,14 @@
 use anchor_lang::prelude::*;
+use crate::state::DocumentDigest;
 
 #[account]
 pub struct UserAccount {
     pub authority: Pubkey,      // عنوان محفظة المستخدم
     pub user_name: String,           // اسم المستخدم
     pub public_data_uri: String,
+    pub public_data_digest: DocumentDigest,
     pub private_data_uri: String,
+    pub private_data_digest: DocumentDigest,   // digest of the encrypted document
     pub encrypted_key_for_gov: String,
     pub encrypted_key_for_user: String,
     pub role: UserRoles,
@@ -28,7 +31,9 @@ impl UserAccount {
         32 + // authority (Pubkey)
         (4 + Self::MAX_USER_NAME_LEN) + // user_name (String)
         (4 + Self::MAX_PUBLIC_DATA_URI_LEN) + // public_data_uri (String)
+        DocumentDigest::LEN + // public_data_digest
         (4 + Self::MAX_PRIVATE_DATA_URI_LEN) + // private_data_uri (String)
+        DocumentDigest::LEN + // private_data_digest
         (4 + Self::MAX_ENCRYPTED_KEY_LEN) + // encrypted_key_for_gov (String)
         (4 + Self::MAX_ENCRYPTED_KEY_LEN) + // encrypted_key_for_user (String)
         1 + // role (UserRoles enum - 1 byte for discriminant)
@@ -44,7 +49,9 @@ impl UserAccount {
         authority: Pubkey,      // عنوان محفظة المستخدم
         user_name: String,           // اسم المستخدم
         public_data_uri: String,
+        public_data_digest: DocumentDigest,
         private_data_uri: String,
+        private_data_digest: DocumentDigest,
         encrypted_key_for_gov: String,
         encrypted_key_for_user: String,
         role: UserRoles,
@@ -54,7 +61,9 @@ impl UserAccount {
         self.authority = authority;
         self.user_name = user_name;
         self.public_data_uri = public_data_uri;
+        self.public_data_digest = public_data_digest;
         self.private_data_uri = private_data_uri;
+        self.private_data_digest = private_data_digest;
         self.encrypted_key_for_gov = encrypted_key_for_gov;
         self.encrypted_key_for_user = encrypted_key_for_user;
         self.verification_status = VerificationStatus::Pending;
@@ -80,5 +89,9 @@ pub enum UserRoles {
    Normal,
    Inspector,
    ConfirmityExpert,
-   Government   // الدور الحكومي للتحقق من المستخدمين
+   Government,  // الدور الحكومي للتحقق من المستخدمين
+   Dealer,      // معرض سيارات
+   Lender,      // جهة تمويل
+   LawEnforcement, // الشرطة
+   Insurer      // شركة تأمين
 }
\ No newline at end of file
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::{CarAccount, CoOwnership, DelegateAction, Organization, UserAccount, UserRoles, VerificationStatus};
+use crate::errors::{CoOwnershipError, OrganizationError};
+
+// Checks that `authority` may perform `action` on the car: a signing majority
+// for a co-owned car, a member of the owning organization for a fleet car,
+// and otherwise the owner or a delegate.
+pub fn is_car_action_authorized(
+    car: &CarAccount,
+    co_ownership: Option<&CoOwnership>,
+    organization: Option<&Account<Organization>>,
+    authority: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+    now: i64,
+    action: DelegateAction,
+) -> Result<bool> {
+    if car.is_co_owned {
+        let co_ownership = co_ownership.ok_or(CoOwnershipError::CoOwnershipAccountMissing)?;
+        return Ok(co_ownership.approves(authority, remaining_accounts));
+    }
+    if let Some(organization) = organization {
+        if organization.key() == car.owner {
+            return Ok(organization.can_manage_fleet(authority));
+        }
+    }
+    Ok(car.is_authorized(authority, now, action))
+}
+
+// The owner, any co-owner, or a fleet manager of the owning organization,
+// each acting alone. Used for protective actions such as theft reports.
+pub fn has_stake_in_car(
+    car: &CarAccount,
+    co_ownership: Option<&CoOwnership>,
+    organization: Option<&Account<Organization>>,
+    key: &Pubkey,
+) -> bool {
+    if car.owner == *key {
+        return true;
+    }
+    if let Some(co_ownership) = co_ownership {
+        if car.is_co_owned && co_ownership.owners.iter().any(|co_owner| co_owner.owner == *key) {
+            return true;
+        }
+    }
+    organization.is_some_and(|organization| {
+        organization.key() == car.owner && organization.can_manage_fleet(key)
+    })
+}
+
+// The key that takes ownership when `signer` acquires a car: the signer
+// itself, or a verified organization the signer administers.
+pub fn acquiring_owner(
+    signer: &Pubkey,
+    organization: Option<&Account<Organization>>,
+) -> Result<Pubkey> {
+    let Some(organization) = organization else {
+        return Ok(*signer);
+    };
+    require!(
+        organization.verification_status == VerificationStatus::Verified,
+        OrganizationError::OrganizationNotVerified
+    );
+    require!(organization.is_admin(signer), OrganizationError::NotOrganizationAdmin);
+    Ok(organization.key())
+}
+
+pub fn is_law_enforcement(user: Option<&UserAccount>, key: &Pubkey) -> bool {
+    user.is_some_and(|user| {
+        user.authority == *key
+            && user.role == UserRoles::LawEnforcement
+            && user.verification_status == VerificationStatus::Verified
+    })
+}
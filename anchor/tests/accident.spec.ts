@@ -0,0 +1,120 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  TestUser,
+  documentDigest,
+  ensureConfigs,
+  expectAnchorError,
+  findPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  setForSale,
+  u16le,
+} from './helpers';
+
+describe('💥 Car Chain - Accident History', () => {
+  const reportUri = 'ipfs://QmPoliceAccidentReport';
+  const damagedZones = {
+    front: true,
+    rear: false,
+    leftSide: true,
+    rightSide: false,
+    roof: false,
+    underbody: false,
+    engine: false,
+    interior: false,
+  };
+
+  let owner: TestUser;
+  let buyer: TestUser;
+  let police: TestUser;
+  let stranger: TestUser;
+  let vin: string;
+  let car: PublicKey;
+
+  const accidentPda = (index: number) => findPda(Buffer.from('accident'), car.toBuffer(), u16le(index));
+
+  const recordAccident = (reporter: TestUser, severity: { minor: {} } | { severe: {} }) =>
+    program.methods
+      .recordAccident(
+        vin,
+        {
+          accidentDate: new BN(Math.floor(Date.now() / 1000) - 24 * 60 * 60),
+          severity,
+          damagedZones,
+          repairStatus: { notRepaired: {} },
+        },
+        reportUri,
+        documentDigest(reportUri, 'application/pdf')
+      )
+      .accounts({
+        car,
+        accidentRecord: accidentPda(0),
+        reporterUser: reporter.pda,
+        reporter: reporter.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([reporter.keypair])
+      .rpc();
+
+  const updateRepair = (repairStatus: { inProgress: {} } | { repaired: {} }) =>
+    program.methods
+      .updateAccidentRepair(repairStatus)
+      .accounts({
+        accidentRecord: accidentPda(0),
+        authorityUser: police.pda,
+        authority: police.keypair.publicKey,
+      })
+      .signers([police.keypair])
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    stranger = await registerVerifiedUser();
+    police = await registerVerifiedUser({ lawEnforcement: {} });
+    ({ vin, car } = await registerCar(owner.keypair));
+    await setForSale(owner.keypair, vin, new BN(2 * LAMPORTS_PER_SOL));
+  }, 60000);
+
+  it('❌ Should only let inspectors, insurers and police record accidents', async () => {
+    await expectAnchorError(recordAccident(stranger, { minor: {} }), 'NotAuthorizedReporter');
+  }, 15000);
+
+  it('✅ Should record a severe accident and flag the car', async () => {
+    await recordAccident(police, { severe: {} });
+
+    const record = await program.account.accidentRecord.fetch(accidentPda(0));
+    expect(record.accidentIndex).toBe(0);
+    expect(record.reportedBy.toString()).toBe(police.keypair.publicKey.toString());
+    expect(record.reporterRole).toHaveProperty('lawEnforcement');
+    expect(record.severity).toHaveProperty('severe');
+    expect(record.damagedZones).toEqual(damagedZones);
+    expect(record.repairStatus).toHaveProperty('notRepaired');
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.accidentCount).toBe(1);
+    expect(carAccount.hasSevereDamage).toBe(true);
+  }, 15000);
+
+  it('✅ Should only move the repair status forward', async () => {
+    await updateRepair({ repaired: {} });
+    await expectAnchorError(updateRepair({ inProgress: {} }), 'InvalidRepairStatus');
+
+    const record = await program.account.accidentRecord.fetch(accidentPda(0));
+    expect(record.repairStatus).toHaveProperty('repaired');
+    // Repairs do not clear severe damage
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.hasSevereDamage).toBe(true);
+  }, 15000);
+
+  it('❌ Should make buyers acknowledge the full accident history', async () => {
+    await expectAnchorError(requestBuy(buyer, vin, 0), 'AccidentHistoryNotAcknowledged');
+
+    const buyRequest = await requestBuy(buyer, vin, 1);
+    expect(await program.account.buyRequest.fetchNullable(buyRequest)).not.toBeNull();
+  }, 15000);
+});
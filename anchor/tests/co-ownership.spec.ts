@@ -0,0 +1,152 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  FEES,
+  TestUser,
+  acceptBuyRequest,
+  balance,
+  coOwnershipPda,
+  ensureConfigs,
+  expectAnchorError,
+  inspectionConfigPda,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  taxReceiptPda,
+} from './helpers';
+
+describe('👥 Car Chain - Co-Ownership', () => {
+  const price = new BN(2 * LAMPORTS_PER_SOL);
+  const thresholdBps = 7000;
+
+  let primary: TestUser;
+  let partner: TestUser;
+  let buyer: TestUser;
+  let vin: string;
+  let car: PublicKey;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    primary = await registerVerifiedUser();
+    partner = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    ({ vin, car } = await registerCar(primary.keypair));
+  }, 60000);
+
+  const shares = () => [
+    { owner: primary.keypair.publicKey, shareBps: 6000 },
+    { owner: partner.keypair.publicKey, shareBps: 4000 },
+  ];
+
+  const asSigner = (keypair: Keypair) => ({ pubkey: keypair.publicKey, isSigner: true, isWritable: true });
+
+  const enableCoOwnership = (vin: string, car: PublicKey, cosigners: Keypair[]) =>
+    program.methods
+      .enableCoOwnership(vin, shares(), thresholdBps)
+      .accounts({
+        coOwnership: coOwnershipPda(car),
+        car,
+        owner: primary.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(cosigners.map(asSigner))
+      .signers([primary.keypair, ...cosigners])
+      .rpc();
+
+  const setForSale = (cosigners: Keypair[]) =>
+    program.methods
+      .setForSale(vin, price)
+      .accounts({
+        carAccount: car,
+        authority: primary.keypair.publicKey,
+        coOwnership: coOwnershipPda(car),
+        organization: null,
+        inspectionConfig: inspectionConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(cosigners.map(asSigner))
+      .signers([primary.keypair, ...cosigners])
+      .rpc();
+
+  it('❌ Should not give anyone a share without their signature', async () => {
+    await expectAnchorError(enableCoOwnership(vin, car, []), 'CoOwnerSignatureMissing');
+  }, 15000);
+
+  it('✅ Should split the car into shares when every co-owner signs', async () => {
+    await enableCoOwnership(vin, car, [partner.keypair]);
+
+    const coOwnership = await program.account.coOwnership.fetch(coOwnershipPda(car));
+    expect(coOwnership.owners.map(({ owner, shareBps }) => [owner.toString(), shareBps])).toEqual(
+      shares().map(({ owner, shareBps }) => [owner.toString(), shareBps])
+    );
+    expect(coOwnership.approvalThresholdBps).toBe(thresholdBps);
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.isCoOwned).toBe(true);
+  }, 15000);
+
+  it('❌ Should not let a co-owner below the threshold list alone', async () => {
+    await expectAnchorError(setForSale([]), 'NotCarOwner');
+  }, 15000);
+
+  it('✅ Should pay each co-owner their share of the sale', async () => {
+    await setForSale([partner.keypair]);
+    await requestBuy(buyer, vin);
+
+    const proceeds = price.sub(price.muln(FEES.saleFeeBps).divn(10000));
+    const primaryShare = proceeds.muln(6000).divn(10000);
+    const partnerShare = proceeds.sub(primaryShare);
+    const coOwnershipRent = await balance(coOwnershipPda(car));
+    const primaryBefore = await balance(primary.keypair.publicKey);
+    const partnerBefore = await balance(partner.keypair.publicKey);
+
+    const transferIndex = await acceptBuyRequest(primary, buyer, vin, {
+      accounts: { coOwnership: coOwnershipPda(car) },
+      // Every co-owner is passed writable to receive their share
+      remainingAccounts: [
+        { pubkey: primary.keypair.publicKey, isSigner: false, isWritable: true },
+        asSigner(partner.keypair),
+      ],
+      signers: [partner.keypair],
+    });
+
+    const recordRent =
+      (await balance(taxReceiptPda(car, transferIndex))) +
+      (await balance(ownershipRecordPda(car, transferIndex)));
+    expect(await balance(primary.keypair.publicKey)).toBe(
+      primaryBefore + primaryShare.toNumber() + coOwnershipRent - recordRent
+    );
+    expect(await balance(partner.keypair.publicKey)).toBe(partnerBefore + partnerShare.toNumber());
+
+    expect(await program.account.coOwnership.fetchNullable(coOwnershipPda(car))).toBeNull();
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.owner.toString()).toBe(buyer.keypair.publicKey.toString());
+    expect(carAccount.isCoOwned).toBe(false);
+  }, 30000);
+
+  it('✅ Should dissolve co-ownership with enough approval', async () => {
+    const { vin, car } = await registerCar(primary.keypair);
+    await enableCoOwnership(vin, car, [partner.keypair]);
+
+    const dissolve = (cosigners: Keypair[]) =>
+      program.methods
+        .dissolveCoOwnership(vin)
+        .accounts({
+          coOwnership: coOwnershipPda(car),
+          car,
+          owner: primary.keypair.publicKey,
+          authority: primary.keypair.publicKey,
+        })
+        .remainingAccounts(cosigners.map(asSigner))
+        .signers([primary.keypair, ...cosigners])
+        .rpc();
+
+    await expectAnchorError(dissolve([]), 'InsufficientApproval');
+    await dissolve([partner.keypair]);
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.isCoOwned).toBe(false);
+    expect(carAccount.owner.toString()).toBe(primary.keypair.publicKey.toString());
+  }, 30000);
+});
@@ -3,6 +3,14 @@ import { Program } from '@coral-xyz/anchor';
 import { Keypair, LAMPORTS_PER_SOL, SystemProgram } from '@solana/web3.js';
 import { CarChain } from '../target/types/car_chain';
 import fs from 'fs';
+import { createHash } from 'crypto';
+import { ensureConfigs, expectAnchorError, feeConfigPda, registerCar, treasuryPda } from './helpers';
+
+// Digest stored next to an off-chain document URI; the tests hash the URI itself
+const documentDigest = (content: string, mediaType = 'application/json') => ({
+  sha256: Array.from(createHash('sha256').update(content).digest()),
+  mediaType,
+});
 
 describe('🚗 Car Chain - User Management System', () => {
   // Configure provider and program
@@ -66,6 +74,7 @@ describe('🚗 Car Chain - User Management System', () => {
     inspectionStatus: { pending: {} },
     latestInspectionReport: null,
     mileage: 0,
+    category: { commercial: {} },
     isForSale: false,
     salePrice: null,
     bump: 0,
@@ -112,6 +121,9 @@ describe('🚗 Car Chain - User Management System', () => {
       expect(balance).toBeGreaterThan(0);
     }
 
+    // Registration charges the fee configured by the government
+    await ensureConfigs();
+
     // Calculate PDA for first user
     [inspectorPDA, inspectorBump] =
       await anchor.web3.PublicKey.findProgramAddress(
@@ -153,7 +165,9 @@ describe('🚗 Car Chain - User Management System', () => {
           .registerUser(
             inspectors.inspector1.userName,
             inspectors.inspector1.publicDataUri,
+            documentDigest(inspectors.inspector1.publicDataUri),
             inspectors.inspector1.privateDataUri,
+            documentDigest(inspectors.inspector1.privateDataUri, 'application/octet-stream'),
             inspectors.inspector1.encryptedKeyForGov,
             inspectors.inspector1.encryptedKeyForUser,
             inspectors.inspector1.role
@@ -215,7 +229,9 @@ describe('🚗 Car Chain - User Management System', () => {
           .registerUser(
             testUsers.user1.userName,
             testUsers.user1.publicDataUri,
+            documentDigest(testUsers.user1.publicDataUri),
             testUsers.user1.privateDataUri,
+            documentDigest(testUsers.user1.privateDataUri, 'application/octet-stream'),
             testUsers.user1.encryptedKeyForGov,
             testUsers.user1.encryptedKeyForUser,
             testUsers.user1.role
@@ -358,15 +374,18 @@ describe('🚗 Car Chain - User Management System', () => {
           validCarData.inspectionStatus,
           validCarData.latestInspectionReport,
           validCarData.mileage,
+          validCarData.category,
           carBump
         )
         .accounts({
           government: governmentKeypair.publicKey,
           car: carPda,
-          owner: ownerKeypair.publicKey,
+          registrant: ownerKeypair.publicKey,
+          feeConfig: feeConfigPda,
+          treasury: treasuryPda,
           systemProgram: SystemProgram.programId,
         })
-        .signers([governmentKeypair])
+        .signers([governmentKeypair, ownerKeypair])
         .rpc();
       console.log(tx4);
       console.log(`✅ Registration transaction: ${tx4}`);
@@ -389,6 +408,7 @@ describe('🚗 Car Chain - User Management System', () => {
         validCarData.latestInspectionReport
       );
       expect(account.mileage).toBe(validCarData.mileage);
+      expect(account.category).toHaveProperty('commercial');
       expect(account.isForSale).toBe(false);
       expect(account.salePrice).toBeNull();
       expect(account.bump).toBe(carBump);
@@ -433,6 +453,7 @@ describe('📝 Conformity Report Tests', () => {
           reportData.conformityStatus,
           reportData.modifications,
           reportData.fullReportUri,
+          documentDigest(reportData.fullReportUri, 'application/pdf'),
           reportData.minesStamp,
           reportData.notes
         )
@@ -469,6 +490,79 @@ describe('📝 Conformity Report Tests', () => {
     }
   }, 15000);
 
+  it('❌ Should reject acceptance by someone other than the car owner', async () => {
+    const stranger = Keypair.generate();
+
+    await expectAnchorError(
+      program.methods
+        .acceptConfirmityReport(reportData.reportId, reportData.vin, inspectorKeypair.publicKey)
+        .accounts({
+          conformityReport: conformityReportPda,
+          car: carPda,
+          owner: stranger.publicKey,
+        })
+        .signers([stranger])
+        .rpc(),
+      'NotCarOwner'
+    );
+
+    console.log('✅ Non-owner acceptance rejected');
+  }, 15000);
+
+  it('❌ Should reject a report that was not issued by the given issuer', async () => {
+    await expectAnchorError(
+      program.methods
+        .acceptConfirmityReport(reportData.reportId, reportData.vin, ownerKeypair.publicKey)
+        .accounts({
+          conformityReport: conformityReportPda,
+          car: carPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc(),
+      'ConstraintSeeds'
+    );
+
+    console.log('✅ Foreign issuer rejected');
+  }, 15000);
+
+  it('❌ Should reject a report presented under another report id', async () => {
+    await expectAnchorError(
+      program.methods
+        .acceptConfirmityReport(reportData.reportId.addn(1), reportData.vin, inspectorKeypair.publicKey)
+        .accounts({
+          conformityReport: conformityReportPda,
+          car: carPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc(),
+      'ConstraintSeeds'
+    );
+
+    console.log('✅ Mismatched report id rejected');
+  }, 15000);
+
+  it('❌ Should reject a report presented against another car', async () => {
+    // A real car of the same owner, so only the report's car seed can fail
+    const { vin: otherVin, car: otherCarPda } = await registerCar(ownerKeypair);
+
+    await expectAnchorError(
+      program.methods
+        .acceptConfirmityReport(reportData.reportId, otherVin, inspectorKeypair.publicKey)
+        .accounts({
+          conformityReport: conformityReportPda,
+          car: otherCarPda,
+          owner: ownerKeypair.publicKey,
+        })
+        .signers([ownerKeypair])
+        .rpc(),
+      'ConstraintSeeds'
+    );
+
+    console.log('✅ Foreign car rejected');
+  }, 15000);
+
   it('✅ Should allow car owner to accept inspection report', async () => {
   console.log('🔄 Testing report acceptance...');
 
@@ -476,7 +570,7 @@ describe('📝 Conformity Report Tests', () => {
 
   try {
     const tx = await program.methods
-      .acceptConfirmityReport(reportData.reportId)
+      .acceptConfirmityReport(reportData.reportId, reportData.vin, inspectorKeypair.publicKey)
       .accounts({
         conformityReport: conformityReportPda,
         car: carPda,
@@ -491,7 +585,7 @@ describe('📝 Conformity Report Tests', () => {
     const reportAccount = await program.account.conformityReport.fetch(conformityReportPda);
       console.log(reportAccount)
     // Verify report was accepted
-    expect(reportAccount.acceptedByOwner).toBe(true);
+    expect(reportAccount.status).toHaveProperty('accepted');
     
     console.log('✅ Successfully verified report acceptance');
   } catch (error) {
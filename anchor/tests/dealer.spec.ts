@@ -0,0 +1,167 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  FEES,
+  TestUser,
+  acceptBuyRequest,
+  balance,
+  consignmentPda,
+  dealerPda,
+  ensureConfigs,
+  expectAnchorError,
+  inspectionConfigPda,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  taxReceiptPda,
+} from './helpers';
+
+describe('🏪 Car Chain - Dealers and Consignment', () => {
+  const commissionBps = 1000;
+  const prices = [new BN(2 * LAMPORTS_PER_SOL), new BN(3 * LAMPORTS_PER_SOL)];
+
+  let dealerUser: TestUser;
+  let owner: TestUser;
+  let buyer: TestUser;
+  let dealer: PublicKey;
+  let cars: { vin: string; car: PublicKey }[];
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    dealerUser = await registerVerifiedUser({ dealer: {} });
+    owner = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    cars = [await registerCar(owner.keypair), await registerCar(owner.keypair)];
+    dealer = dealerPda(dealerUser.keypair.publicKey);
+  }, 60000);
+
+  const batchSetForSale = (authority: TestUser) =>
+    program.methods
+      .batchSetForSale(prices)
+      .accounts({
+        dealer,
+        inspectionConfig: inspectionConfigPda,
+        authority: authority.keypair.publicKey,
+      })
+      .remainingAccounts(cars.map(({ car }) => ({ pubkey: car, isSigner: false, isWritable: true })))
+      .signers([authority.keypair])
+      .rpc();
+
+  it('✅ Should register a dealer for a verified dealer user', async () => {
+    await program.methods
+      .registerDealer('DLR-0001')
+      .accounts({
+        dealer,
+        dealerUser: dealerUser.pda,
+        dealerSigner: dealerUser.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([dealerUser.keypair])
+      .rpc();
+
+    const account = await program.account.dealerAccount.fetch(dealer);
+    expect(account.authority.toString()).toBe(dealerUser.keypair.publicKey.toString());
+    expect(account.licenceNumber).toBe('DLR-0001');
+    expect(account.activeConsignments).toBe(0);
+  }, 15000);
+
+  it('✅ Should consign both cars to the dealer', async () => {
+    for (const { vin, car } of cars) {
+      await program.methods
+        .consignCar(vin, commissionBps)
+        .accounts({
+          consignment: consignmentPda(car),
+          car,
+          dealer,
+          owner: owner.keypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner.keypair])
+        .rpc();
+
+      const account = await program.account.carAccount.fetch(car);
+      expect(account.consignedTo?.toString()).toBe(dealer.toString());
+    }
+
+    const account = await program.account.dealerAccount.fetch(dealer);
+    expect(account.activeConsignments).toBe(2);
+  }, 30000);
+
+  it('❌ Should reject a batch listing from someone outside the dealership', async () => {
+    await expectAnchorError(batchSetForSale(buyer), 'UnauthorizedDealerStaff');
+  }, 15000);
+
+  it('✅ Should list the whole consignment in one batch', async () => {
+    await batchSetForSale(dealerUser);
+
+    for (const [i, { car }] of cars.entries()) {
+      const account = await program.account.carAccount.fetch(car);
+      expect(account.isForSale).toBe(true);
+      expect(account.salePrice?.toString()).toBe(prices[i].toString());
+    }
+  }, 15000);
+
+  it('✅ Should split the sale between the owner and the dealer commission', async () => {
+    const [{ vin, car }] = cars;
+    const price = prices[0];
+    const saleFee = price.muln(FEES.saleFeeBps).divn(10000);
+    const commission = price.sub(saleFee).muln(commissionBps).divn(10000);
+    const proceeds = price.sub(saleFee).sub(commission);
+
+    await requestBuy(buyer, vin);
+
+    const consignment = consignmentPda(car);
+    const consignmentRent = await balance(consignment);
+    const ownerBefore = await balance(owner.keypair.publicKey);
+    const dealerWalletBefore = await balance(dealerUser.keypair.publicKey);
+
+    // Accepted by the dealer, who pays the rent of the sale records
+    const transferIndex = await acceptBuyRequest(owner, buyer, vin, {
+      authority: dealerUser.keypair,
+      accounts: { consignment, dealer, dealerWallet: dealerUser.keypair.publicKey },
+    });
+
+    expect(await balance(owner.keypair.publicKey)).toBe(
+      ownerBefore + proceeds.toNumber() + consignmentRent
+    );
+    const recordRent =
+      (await balance(taxReceiptPda(car, transferIndex))) +
+      (await balance(ownershipRecordPda(car, transferIndex)));
+    expect(await balance(dealerUser.keypair.publicKey)).toBe(
+      dealerWalletBefore + commission.toNumber() - recordRent
+    );
+
+    expect(await program.account.consignment.fetchNullable(consignment)).toBeNull();
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.owner.toString()).toBe(buyer.keypair.publicKey.toString());
+    expect(carAccount.consignedTo).toBeNull();
+    const dealerAccount = await program.account.dealerAccount.fetch(dealer);
+    expect(dealerAccount.activeConsignments).toBe(1);
+    console.log(`✅ Dealer earned ${commission.toString()} lamports commission`);
+  }, 15000);
+
+  it('✅ Should let the owner end a consignment and drop the dealer listing', async () => {
+    const [, { vin, car }] = cars;
+
+    await program.methods
+      .endConsignment(vin)
+      .accounts({
+        consignment: consignmentPda(car),
+        car,
+        dealer,
+        owner: owner.keypair.publicKey,
+        authority: owner.keypair.publicKey,
+      })
+      .signers([owner.keypair])
+      .rpc();
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.consignedTo).toBeNull();
+    expect(carAccount.isForSale).toBe(false);
+    expect(carAccount.salePrice).toBeNull();
+    const dealerAccount = await program.account.dealerAccount.fetch(dealer);
+    expect(dealerAccount.activeConsignments).toBe(0);
+  }, 15000);
+});
@@ -0,0 +1,188 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  FEES,
+  TestUser,
+  acceptBuyRequest,
+  balance,
+  buyRequestPda,
+  carPda,
+  ensureConfigs,
+  expectAnchorError,
+  fundedKeypair,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  setForSale,
+  sleep,
+  taxReceiptPda,
+} from './helpers';
+
+describe('🤝 Car Chain - Sale Delegates', () => {
+  const price = new BN(2 * LAMPORTS_PER_SOL);
+  const inOneHour = () => new BN(Math.floor(Date.now() / 1000) + 60 * 60);
+
+  let owner: TestUser;
+  let buyer: TestUser;
+  let delegate: Keypair;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    delegate = await fundedKeypair();
+  }, 60000);
+
+  const approveDelegate = (vin: string, scope: object, expiresAt: BN, signer = owner.keypair) =>
+    program.methods
+      .approveDelegate(vin, delegate.publicKey, scope, expiresAt)
+      .accounts({ carAccount: carPda(vin), owner: signer.publicKey })
+      .signers([signer])
+      .rpc();
+
+  describe('🏷️ Delegate allowed to list and accept offers', () => {
+    let vin: string;
+    let car: PublicKey;
+
+    beforeAll(async () => {
+      ({ vin, car } = await registerCar(owner.keypair));
+    }, 30000);
+
+    it('❌ Should only let the owner appoint a delegate', async () => {
+      await expectAnchorError(
+        approveDelegate(vin, { list: true, acceptOffers: true, transfer: false }, inOneHour(), buyer.keypair),
+        'NotCarOwner'
+      );
+    }, 15000);
+
+    it('✅ Should record the delegate and its scope', async () => {
+      const expiresAt = inOneHour();
+      await approveDelegate(vin, { list: true, acceptOffers: true, transfer: false }, expiresAt);
+
+      const account = await program.account.carAccount.fetch(car);
+      expect(account.delegate?.delegate.toString()).toBe(delegate.publicKey.toString());
+      expect(account.delegate?.scope).toEqual({ list: true, acceptOffers: true, transfer: false });
+      expect(account.delegate?.expiresAt.toString()).toBe(expiresAt.toString());
+    }, 15000);
+
+    it('✅ Should let the delegate list the car and accept an offer for the owner', async () => {
+      await setForSale(delegate, vin, price);
+      await requestBuy(buyer, vin);
+
+      const saleFee = price.muln(FEES.saleFeeBps).divn(10000).toNumber();
+      const ownerBefore = await balance(owner.keypair.publicKey);
+      const delegateBefore = await balance(delegate.publicKey);
+
+      const transferIndex = await acceptBuyRequest(owner, buyer, vin, { authority: delegate });
+
+      // The proceeds go to the owner; the delegate only pays the records' rent
+      const recordRent =
+        (await balance(taxReceiptPda(car, transferIndex))) +
+        (await balance(ownershipRecordPda(car, transferIndex)));
+      expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore + price.toNumber() - saleFee);
+      expect(await balance(delegate.publicKey)).toBe(delegateBefore - recordRent);
+
+      // A sale ends the delegation
+      const account = await program.account.carAccount.fetch(car);
+      expect(account.owner.toString()).toBe(buyer.keypair.publicKey.toString());
+      expect(account.delegate).toBeNull();
+    }, 15000);
+  });
+
+  describe('⏳ Delegate limited in scope and time', () => {
+    let vin: string;
+
+    beforeAll(async () => {
+      ({ vin } = await registerCar(owner.keypair));
+    }, 30000);
+
+    it('❌ Should refuse offers from a delegate only allowed to list', async () => {
+      await approveDelegate(vin, { list: true, acceptOffers: false, transfer: false }, inOneHour());
+      await setForSale(delegate, vin, price);
+      await requestBuy(buyer, vin);
+
+      await expectAnchorError(
+        acceptBuyRequest(owner, buyer, vin, { authority: delegate }),
+        'UnauthorizedAccess'
+      );
+    }, 30000);
+
+    it('❌ Should refuse a delegate once its approval has expired', async () => {
+      const expiresAt = new BN(Math.floor(Date.now() / 1000) + 4);
+      await approveDelegate(vin, { list: true, acceptOffers: false, transfer: false }, expiresAt);
+      await sleep(6);
+
+      await expectAnchorError(
+        program.methods
+          .updateListingPrice(vin, price.muln(2))
+          .accounts({
+            carAccount: carPda(vin),
+            authority: delegate.publicKey,
+            coOwnership: null,
+            organization: null,
+          })
+          .signers([delegate])
+          .rpc(),
+        'NotCarOwner'
+      );
+    }, 30000);
+
+    it('✅ Should let the owner revoke the delegate', async () => {
+      await program.methods
+        .revokeDelegate(vin)
+        .accounts({ carAccount: carPda(vin), owner: owner.keypair.publicKey })
+        .signers([owner.keypair])
+        .rpc();
+
+      const account = await program.account.carAccount.fetch(carPda(vin));
+      expect(account.delegate).toBeNull();
+    }, 15000);
+  });
+
+  describe('🚫 Rejecting offers', () => {
+    let seller: TestUser;
+    let ownVin: string;
+    let otherVin: string;
+
+    const reject = (vin: string, car: PublicKey, signer: Keypair) =>
+      program.methods
+        .rejectBuyRequest(vin)
+        .accounts({
+          seller: signer.publicKey,
+          car,
+          coOwnership: null,
+          organization: null,
+          buyRequest: buyRequestPda(vin, buyer.keypair.publicKey),
+          buyer: buyer.keypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([signer])
+        .rpc();
+
+    beforeAll(async () => {
+      seller = await registerVerifiedUser();
+      ({ vin: ownVin } = await registerCar(owner.keypair));
+      ({ vin: otherVin } = await registerCar(seller.keypair));
+      await setForSale(owner.keypair, ownVin, price);
+      await setForSale(seller.keypair, otherVin, price);
+      await requestBuy(buyer, otherVin);
+    }, 60000);
+
+    it('❌ Should not let an owner reject offers on another car by passing their own', async () => {
+      await expectAnchorError(reject(otherVin, carPda(ownVin), owner.keypair), 'ConstraintSeeds');
+    }, 15000);
+
+    it('✅ Should let the owner reject the offer and refund the buyer', async () => {
+      const buyRequest = buyRequestPda(otherVin, buyer.keypair.publicKey);
+      const escrowed = await balance(buyRequest);
+      const buyerBefore = await balance(buyer.keypair.publicKey);
+
+      await reject(otherVin, carPda(otherVin), seller.keypair);
+
+      expect(await program.account.buyRequest.fetchNullable(buyRequest)).toBeNull();
+      expect(await balance(buyer.keypair.publicKey)).toBe(buyerBefore + escrowed);
+    }, 15000);
+  });
+});
@@ -0,0 +1,114 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  TestUser,
+  carPda,
+  documentDigest,
+  ensureConfigs,
+  expectAnchorError,
+  findPda,
+  governmentKeypair,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  setForSale,
+  u16le,
+} from './helpers';
+
+describe('🗑️ Car Chain - Deregistration', () => {
+  const certificateUri = 'ipfs://QmExportCertificate';
+
+  let owner: TestUser;
+  let importer: Keypair;
+
+  const deregistrationPda = (car: PublicKey, registrationNumber: number) =>
+    findPda(Buffer.from('deregistration'), car.toBuffer(), u16le(registrationNumber));
+
+  const deregister = (
+    vin: string,
+    reason: { exported: {} } | { scrapped: {} },
+    government = governmentKeypair
+  ) =>
+    program.methods
+      .deregisterCar(vin, reason, certificateUri, documentDigest(certificateUri, 'application/pdf'))
+      .accounts({
+        car: carPda(vin),
+        deregistrationRecord: deregistrationPda(carPda(vin), 1),
+        coOwnership: null,
+        owner: owner.keypair.publicKey,
+        government: government.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([government])
+      .rpc();
+
+  const reregister = (vin: string) =>
+    program.methods
+      .reregisterCar(vin, importer.publicKey, 42000)
+      .accounts({
+        car: carPda(vin),
+        deregistrationRecord: deregistrationPda(carPda(vin), 1),
+        ownershipRecord: ownershipRecordPda(carPda(vin), 0),
+        government: governmentKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([governmentKeypair])
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    importer = Keypair.generate();
+  }, 60000);
+
+  it('❌ Should only let the government deregister a car', async () => {
+    const { vin } = await registerCar(owner.keypair);
+
+    await expectAnchorError(deregister(vin, { exported: {} }, owner.keypair), 'UnauthorizedVerifier');
+  }, 15000);
+
+  it('✅ Should deregister an exported car and bring it back on re-import', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+
+    await deregister(vin, { exported: {} });
+
+    const record = await program.account.deregistrationRecord.fetch(deregistrationPda(car, 1));
+    expect(record.registrationNumber).toBe(1);
+    expect(record.reason).toHaveProperty('exported');
+    expect(record.certificateUri).toBe(certificateUri);
+    expect(record.owner.toString()).toBe(owner.keypair.publicKey.toString());
+    expect(record.reregisteredAt).toBeNull();
+    let carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.isActive).toBe(false);
+    expect(carAccount.lastDeregistration?.toString()).toBe(deregistrationPda(car, 1).toString());
+
+    await expectAnchorError(
+      setForSale(owner.keypair, vin, new BN(LAMPORTS_PER_SOL)),
+      'CarDeregistered'
+    );
+
+    await reregister(vin);
+
+    carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.isActive).toBe(true);
+    expect(carAccount.owner.toString()).toBe(importer.publicKey.toString());
+    expect(carAccount.registrationCount).toBe(2);
+    expect(carAccount.mileage).toBe(42000);
+    expect(carAccount.lastDeregistration).toBeNull();
+
+    const ownershipRecord = await program.account.ownershipRecord.fetch(ownershipRecordPda(car, 0));
+    expect(ownershipRecord.previousOwner.toString()).toBe(owner.keypair.publicKey.toString());
+    expect(ownershipRecord.newOwner.toString()).toBe(importer.publicKey.toString());
+    expect(ownershipRecord.mechanism).toHaveProperty('reimport');
+    const closed = await program.account.deregistrationRecord.fetch(deregistrationPda(car, 1));
+    expect(closed.reregisteredAt).not.toBeNull();
+  }, 30000);
+
+  it('❌ Should not re-register a scrapped car', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    await deregister(vin, { scrapped: {} });
+
+    await expectAnchorError(reregister(vin), 'ReregistrationNotAllowed');
+  }, 30000);
+});
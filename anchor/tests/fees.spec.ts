@@ -0,0 +1,111 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  FEES,
+  TestUser,
+  acceptBuyRequest,
+  balance,
+  ensureConfigs,
+  expectAnchorError,
+  governmentKeypair,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  setForSale,
+  taxReceiptPda,
+  treasuryPda,
+} from './helpers';
+
+describe('💳 Car Chain - Platform Fees', () => {
+  const price = new BN(2 * LAMPORTS_PER_SOL);
+  const saleFee = price.muln(FEES.saleFeeBps).divn(10000).toNumber();
+
+  let seller: TestUser;
+  let buyer: TestUser;
+  let vin: string;
+  let car: PublicKey;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    seller = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+  }, 60000);
+
+  it('✅ Should charge the registration fee to the registrant', async () => {
+    const treasuryBefore = await program.account.treasury.fetch(treasuryPda);
+    const treasuryLamports = await balance(treasuryPda);
+    const sellerBefore = await balance(seller.keypair.publicKey);
+
+    ({ vin, car } = await registerCar(seller.keypair));
+
+    const treasuryAfter = await program.account.treasury.fetch(treasuryPda);
+    expect(await balance(treasuryPda)).toBe(treasuryLamports + FEES.registrationFee.toNumber());
+    expect(treasuryAfter.totalCollected.sub(treasuryBefore.totalCollected).toString()).toBe(
+      FEES.registrationFee.toString()
+    );
+    // The government pays the car account's rent, the registrant only the fee
+    expect(await balance(seller.keypair.publicKey)).toBe(
+      sellerBefore - FEES.registrationFee.toNumber()
+    );
+    console.log('✅ Registration fee collected');
+  }, 15000);
+
+  it('✅ Should take the sale fee out of the seller proceeds', async () => {
+    await setForSale(seller.keypair, vin, price);
+    await requestBuy(buyer, vin);
+
+    const treasuryLamports = await balance(treasuryPda);
+    const sellerBefore = await balance(seller.keypair.publicKey);
+
+    const transferIndex = await acceptBuyRequest(seller, buyer, vin);
+
+    // The seller also pays the rent of the receipt and ownership record it creates
+    const recordRent =
+      (await balance(taxReceiptPda(car, transferIndex))) +
+      (await balance(ownershipRecordPda(car, transferIndex)));
+
+    expect(await balance(treasuryPda)).toBe(treasuryLamports + saleFee);
+    expect(await balance(seller.keypair.publicKey)).toBe(
+      sellerBefore + price.toNumber() - saleFee - recordRent
+    );
+    console.log(`✅ Sale fee of ${saleFee} lamports collected`);
+  }, 15000);
+
+  it('❌ Should reject a treasury withdrawal by anyone but the government', async () => {
+    const impostor = Keypair.generate();
+
+    await expectAnchorError(
+      program.methods
+        .withdrawTreasury(new BN(saleFee))
+        .accounts({
+          treasury: treasuryPda,
+          government: impostor.publicKey,
+          recipient: impostor.publicKey,
+        })
+        .signers([impostor])
+        .rpc(),
+      'UnauthorizedVerifier'
+    );
+  }, 15000);
+
+  it('✅ Should let the government withdraw collected fees', async () => {
+    const recipient = Keypair.generate();
+    const treasuryLamports = await balance(treasuryPda);
+
+    await program.methods
+      .withdrawTreasury(new BN(saleFee))
+      .accounts({
+        treasury: treasuryPda,
+        government: governmentKeypair.publicKey,
+        recipient: recipient.publicKey,
+      })
+      .signers([governmentKeypair])
+      .rpc();
+
+    expect(await balance(recipient.publicKey)).toBe(saleFee);
+    expect(await balance(treasuryPda)).toBe(treasuryLamports - saleFee);
+    console.log('✅ Fees withdrawn');
+  }, 15000);
+});
@@ -0,0 +1,183 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  TestUser,
+  balance,
+  buyRequestPda,
+  carPda,
+  documentDigest,
+  ensureConfigs,
+  expectAnchorError,
+  findPda,
+  governmentKeypair,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  setForSale,
+  u32le,
+} from './helpers';
+
+describe('⚖️ Car Chain - Government Forced Transfers', () => {
+  const courtOrderUri = 'ipfs://QmInheritanceDecree';
+
+  let owner: TestUser;
+  let buyer: TestUser;
+  let heir: Keypair;
+  let vin: string;
+  let car: PublicKey;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    heir = Keypair.generate();
+    ({ vin, car } = await registerCar(owner.keypair));
+    await setForSale(owner.keypair, vin, new BN(2 * LAMPORTS_PER_SOL));
+    await requestBuy(buyer, vin);
+  }, 60000);
+
+  const planPda = (car: PublicKey) => findPda(Buffer.from('installment_plan'), car.toBuffer());
+
+  const forceTransferOf = (
+    carVin: string,
+    previousOwner: PublicKey,
+    government: Keypair,
+    { installmentPlan = null as PublicKey | null, installmentBuyer = null as PublicKey | null } = {}
+  ) => {
+    const carKey = carPda(carVin);
+
+    return program.methods
+      .forceTransfer(
+        carVin,
+        heir.publicKey,
+        { inheritance: {} },
+        courtOrderUri,
+        documentDigest(courtOrderUri, 'application/pdf')
+      )
+      .accounts({
+        car: carKey,
+        ownershipRecord: ownershipRecordPda(carKey, 0),
+        forcedTransferRecord: findPda(Buffer.from('forced_transfer'), carKey.toBuffer(), u32le(0)),
+        consignment: null,
+        dealer: null,
+        coOwnership: null,
+        installmentPlan,
+        installmentBuyer,
+        previousOwner,
+        government: government.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([government]);
+  };
+
+  const forceTransfer = (government: Keypair) =>
+    forceTransferOf(vin, owner.keypair.publicKey, government)
+      .remainingAccounts([
+        { pubkey: buyRequestPda(vin, buyer.keypair.publicKey), isSigner: false, isWritable: true },
+        { pubkey: buyer.keypair.publicKey, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+  it('❌ Should only let the government force a transfer', async () => {
+    await expectAnchorError(forceTransfer(owner.keypair), 'UnauthorizedVerifier');
+  }, 15000);
+
+  it('✅ Should hand the car to the heir and refund open buy requests', async () => {
+    const buyRequest = buyRequestPda(vin, buyer.keypair.publicKey);
+    const escrowed = await balance(buyRequest);
+    const buyerBefore = await balance(buyer.keypair.publicKey);
+
+    await forceTransfer(governmentKeypair);
+
+    expect(await program.account.buyRequest.fetchNullable(buyRequest)).toBeNull();
+    expect(await balance(buyer.keypair.publicKey)).toBe(buyerBefore + escrowed);
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.owner.toString()).toBe(heir.publicKey.toString());
+    expect(carAccount.isForSale).toBe(false);
+    expect(carAccount.salePrice).toBeNull();
+    expect(carAccount.transferCount).toBe(1);
+
+    const record = await program.account.ownershipRecord.fetch(ownershipRecordPda(car, 0));
+    expect(record.previousOwner.toString()).toBe(owner.keypair.publicKey.toString());
+    expect(record.newOwner.toString()).toBe(heir.publicKey.toString());
+    expect(record.mechanism).toHaveProperty('forced');
+
+    const forced = await program.account.forcedTransferRecord.fetch(
+      findPda(Buffer.from('forced_transfer'), car.toBuffer(), u32le(0))
+    );
+    expect(forced.reason).toHaveProperty('inheritance');
+    expect(forced.documentUri).toBe(courtOrderUri);
+    expect(forced.orderedBy.toString()).toBe(governmentKeypair.publicKey.toString());
+  }, 15000);
+
+  describe('📆 Car under an instalment plan', () => {
+    const downPayment = new BN(0.5 * LAMPORTS_PER_SOL);
+
+    let planVin: string;
+    let planCar: PublicKey;
+
+    beforeAll(async () => {
+      ({ vin: planVin, car: planCar } = await registerCar(owner.keypair));
+
+      await program.methods
+        .proposeInstallmentPlan(planVin, buyer.keypair.publicKey, {
+          downPayment,
+          installmentAmount: new BN(0.25 * LAMPORTS_PER_SOL),
+          installmentCount: 2,
+          intervalSeconds: new BN(60 * 60),
+          gracePeriodSeconds: new BN(60 * 60),
+          defaultRefundBps: 5000,
+        })
+        .accounts({
+          installmentPlan: planPda(planCar),
+          car: planCar,
+          seller: owner.keypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner.keypair])
+        .rpc();
+
+      await program.methods
+        .acceptInstallmentPlan(planVin)
+        .accounts({
+          installmentPlan: planPda(planCar),
+          car: planCar,
+          buyer: buyer.keypair.publicKey,
+          buyerPda: buyer.pda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([buyer.keypair])
+        .rpc();
+    }, 60000);
+
+    it('❌ Should refuse to leave the plan behind', async () => {
+      await expectAnchorError(
+        forceTransferOf(planVin, owner.keypair.publicKey, governmentKeypair).rpc(),
+        'CarUnderInstallmentPlan'
+      );
+    }, 15000);
+
+    it('✅ Should close the plan and refund the instalment buyer', async () => {
+      const plan = planPda(planCar);
+      const planRent = (await balance(plan)) - downPayment.toNumber();
+      const buyerBefore = await balance(buyer.keypair.publicKey);
+      const ownerBefore = await balance(owner.keypair.publicKey);
+
+      await forceTransferOf(planVin, owner.keypair.publicKey, governmentKeypair, {
+        installmentPlan: plan,
+        installmentBuyer: buyer.keypair.publicKey,
+      }).rpc();
+
+      expect(await program.account.installmentPlan.fetchNullable(plan)).toBeNull();
+      expect(await balance(buyer.keypair.publicKey)).toBe(buyerBefore + downPayment.toNumber());
+      expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore + planRent);
+
+      const carAccount = await program.account.carAccount.fetch(planCar);
+      expect(carAccount.owner.toString()).toBe(heir.publicKey.toString());
+      expect(carAccount.installmentPlan).toBeNull();
+    }, 15000);
+  });
+});
@@ -0,0 +1,566 @@
+import * as anchor from '@coral-xyz/anchor';
+import { BN, Program } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import { CarChain } from '../target/types/car_chain';
+import fs from 'fs';
+import { createHash, randomBytes } from 'crypto';
+
+// Shared setup for the spec files. Fee, tax and inspection settings live in
+// singleton PDAs, so every spec writes the same values through ensureConfigs()
+// and jest runs the specs one file at a time.
+
+export const provider = anchor.AnchorProvider.env();
+anchor.setProvider(provider);
+export const program = anchor.workspace.CarChain as Program<CarChain>;
+
+export const governmentKeypair = Keypair.fromSecretKey(
+  new Uint8Array(JSON.parse(fs.readFileSync('./keys/government-keypair.json', 'utf8')))
+);
+
+export const FEES = {
+  saleFeeBps: 250,
+  registrationFee: new BN(0.01 * LAMPORTS_PER_SOL),
+  transferFee: new BN(0.005 * LAMPORTS_PER_SOL),
+};
+
+// Cars in the specs are registered as 2010 models, so they always fall in
+// the last age bracket
+export const TAX = {
+  ageBrackets: [
+    { maxAge: 3, rateBps: 500 },
+    { maxAge: 10, rateBps: 300 },
+  ],
+  priceBrackets: [{ minPrice: new BN(10 * LAMPORTS_PER_SOL), surchargeBps: 200 }],
+  flatTransferTax: new BN(0.002 * LAMPORTS_PER_SOL),
+};
+
+export const INSPECTION_TYPES = [{ periodic: {} }, { preSale: {} }, { postAccident: {} }, { custom: {} }];
+
+// Every ChecklistItem marked Pass (two bits each), with plausible measurements
+export const passingChecklist = {
+  results: 0x555555,
+  tyreTreadDepth: [45, 45, 40, 40],
+  brakeEfficiency: 70,
+  parkingBrakeEfficiency: 25,
+  coEmissions: 20,
+};
+
+// Heavy vehicles get a validity period short enough to watch it lapse
+export const INSPECTION = {
+  validityRules: [{ category: { heavy: {} }, maxAge: 50, validitySeconds: new BN(5) }],
+  defaultValiditySeconds: new BN(365 * 24 * 60 * 60),
+  requireValidInspectionForSale: false,
+  maxVoidedReports: 1,
+};
+
+// Digest stored next to an off-chain document URI; the tests hash the URI itself
+export const documentDigest = (content: string, mediaType = 'application/json') => ({
+  sha256: Array.from(createHash('sha256').update(content).digest()),
+  mediaType,
+});
+
+export const findPda = (...seeds: (Buffer | Uint8Array)[]) =>
+  PublicKey.findProgramAddressSync(seeds, program.programId)[0];
+
+export const u16le = (value: number) => new BN(value).toArrayLike(Buffer, 'le', 2);
+export const u32le = (value: number) => new BN(value).toArrayLike(Buffer, 'le', 4);
+export const u64le = (value: BN) => value.toArrayLike(Buffer, 'le', 8);
+
+export const feeConfigPda = findPda(Buffer.from('fee_config'));
+export const treasuryPda = findPda(Buffer.from('treasury'));
+export const taxConfigPda = findPda(Buffer.from('tax_config'));
+export const taxTreasuryPda = findPda(Buffer.from('tax_treasury'));
+export const inspectionConfigPda = findPda(Buffer.from('inspection_config'));
+
+export const userPda = (wallet: PublicKey, userName: string) =>
+  findPda(Buffer.from('user'), wallet.toBuffer(), Buffer.from(userName));
+
+export const carPda = (vin: string) =>
+  findPda(Buffer.from('car'), governmentKeypair.publicKey.toBuffer(), Buffer.from(vin));
+
+export const buyRequestPda = (vin: string, buyer: PublicKey) =>
+  findPda(Buffer.from('buy_request'), Buffer.from(vin), buyer.toBuffer());
+
+export const ownershipRecordPda = (car: PublicKey, transferIndex: number) =>
+  findPda(Buffer.from('ownership_record'), car.toBuffer(), u32le(transferIndex));
+
+export const taxReceiptPda = (car: PublicKey, transferIndex: number) =>
+  findPda(Buffer.from('tax_receipt'), car.toBuffer(), u32le(transferIndex));
+
+export const pendingTransferPda = (car: PublicKey) =>
+  findPda(Buffer.from('pending_transfer'), car.toBuffer());
+
+export const coOwnershipPda = (car: PublicKey) =>
+  findPda(Buffer.from('co_ownership'), car.toBuffer());
+
+export const dealerPda = (wallet: PublicKey) => findPda(Buffer.from('dealer'), wallet.toBuffer());
+
+export const consignmentPda = (car: PublicKey) => findPda(Buffer.from('consignment'), car.toBuffer());
+
+export const inspectionCenterPda = (licenceNumber: string) =>
+  findPda(Buffer.from('inspection_center'), Buffer.from(licenceNumber));
+
+export const inspectionRequestPda = (car: PublicKey) =>
+  findPda(Buffer.from('inspection_request'), car.toBuffer());
+
+// Seeded by the inspector's UserAccount, not their wallet
+export const inspectorStatsPda = (inspector: PublicKey) =>
+  findPda(Buffer.from('inspector_stats'), inspector.toBuffer());
+
+export const carReportPda = (car: PublicKey, inspectorWallet: PublicKey, reportId: BN) =>
+  findPda(Buffer.from('car_report'), car.toBuffer(), inspectorWallet.toBuffer(), u64le(reportId));
+
+// 17 characters, unique per call so specs never collide on the shared validator
+export const randomVin = () => `T${randomBytes(8).toString('hex').toUpperCase()}`;
+
+export const balance = (address: PublicKey) => provider.connection.getBalance(address);
+
+// Rent-exempt minimum of an existing account, to separate escrowed lamports from rent
+export const rentOf = async (address: PublicKey) => {
+  const info = await provider.connection.getAccountInfo(address);
+  return provider.connection.getMinimumBalanceForRentExemption(info!.data.length);
+};
+
+export const airdrop = async (address: PublicKey, sol = 5) => {
+  const signature = await provider.connection.requestAirdrop(address, sol * LAMPORTS_PER_SOL);
+  await provider.connection.confirmTransaction(signature, 'confirmed');
+};
+
+export const fundedKeypair = async (sol = 5) => {
+  const keypair = Keypair.generate();
+  await airdrop(keypair.publicKey, sol);
+  return keypair;
+};
+
+// For paths gated on the validator clock, which follows wall-clock time
+export const sleep = (seconds: number) =>
+  new Promise((resolve) => setTimeout(resolve, seconds * 1000));
+
+export const expectAnchorError = async (promise: Promise<unknown>, code: string) => {
+  await expect(promise).rejects.toMatchObject({ error: { errorCode: { code } } });
+};
+
+export const ensureConfigs = async () => {
+  await airdrop(governmentKeypair.publicKey);
+
+  await program.methods
+    .setFeeConfig(FEES.saleFeeBps, FEES.registrationFee, FEES.transferFee)
+    .accounts({
+      feeConfig: feeConfigPda,
+      treasury: treasuryPda,
+      government: governmentKeypair.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([governmentKeypair])
+    .rpc();
+
+  await program.methods
+    .setTaxConfig(TAX.ageBrackets, TAX.priceBrackets, TAX.flatTransferTax)
+    .accounts({
+      taxConfig: taxConfigPda,
+      taxTreasury: taxTreasuryPda,
+      government: governmentKeypair.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([governmentKeypair])
+    .rpc();
+
+  await program.methods
+    .setInspectionConfig(
+      INSPECTION.validityRules,
+      INSPECTION.defaultValiditySeconds,
+      INSPECTION.requireValidInspectionForSale,
+      INSPECTION.maxVoidedReports
+    )
+    .accounts({
+      inspectionConfig: inspectionConfigPda,
+      government: governmentKeypair.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([governmentKeypair])
+    .rpc();
+};
+
+export type TestUser = { keypair: Keypair; pda: PublicKey; userName: string };
+
+// Registers a funded wallet as a user with the given role and has the government verify it
+export const registerVerifiedUser = async (
+  role: object = { normal: {} },
+  userName = 'Test User'
+): Promise<TestUser> => {
+  const keypair = await fundedKeypair();
+  const pda = userPda(keypair.publicKey, userName);
+  const publicDataUri = `ipfs://public/${keypair.publicKey.toBase58()}`;
+  const privateDataUri = `ipfs://private/${keypair.publicKey.toBase58()}`;
+
+  await program.methods
+    .registerUser(
+      userName,
+      publicDataUri,
+      documentDigest(publicDataUri),
+      privateDataUri,
+      documentDigest(privateDataUri, 'application/octet-stream'),
+      'encrypted_gov_key',
+      'encrypted_user_key',
+      role
+    )
+    .accounts({
+      userAccount: pda,
+      userSigner: keypair.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([keypair])
+    .rpc();
+
+  await program.methods
+    .verifyUser(userName, true)
+    .accounts({ userAccount: pda, government: governmentKeypair.publicKey })
+    .signers([governmentKeypair])
+    .rpc();
+
+  return { keypair, pda, userName };
+};
+
+export const registerCar = async (
+  owner: Keypair,
+  { vin = randomVin(), year = 2010, category = { private: {} } as object, mileage = 0 } = {}
+) => {
+  const car = carPda(vin);
+
+  await program.methods
+    .registerCar(
+      `CAR-${vin.slice(-6)}`,
+      vin,
+      'Toyota',
+      'Corolla',
+      year,
+      'White',
+      `ENG${vin.slice(-8)}`,
+      owner.publicKey,
+      null,
+      { pending: {} },
+      null,
+      mileage,
+      category,
+      0
+    )
+    .accounts({
+      car,
+      government: governmentKeypair.publicKey,
+      registrant: owner.publicKey,
+      feeConfig: feeConfigPda,
+      treasury: treasuryPda,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([governmentKeypair, owner])
+    .rpc();
+
+  return { vin, car };
+};
+
+export const setForSale = async (authority: Keypair, vin: string, price: BN) => {
+  await program.methods
+    .setForSale(vin, price)
+    .accounts({
+      carAccount: carPda(vin),
+      authority: authority.publicKey,
+      coOwnership: null,
+      organization: null,
+      inspectionConfig: inspectionConfigPda,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([authority])
+    .rpc();
+};
+
+export const requestBuy = async (buyer: TestUser, vin: string, acknowledgedAccidentCount = 0) => {
+  const buyRequest = buyRequestPda(vin, buyer.keypair.publicKey);
+
+  await program.methods
+    .requestBuy(vin, null, acknowledgedAccidentCount)
+    .accounts({
+      buyRequest,
+      car: carPda(vin),
+      buyerPda: buyer.pda,
+      taxConfig: taxConfigPda,
+      buyer: buyer.keypair.publicKey,
+      organization: null,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([buyer.keypair])
+    .rpc();
+
+  return buyRequest;
+};
+
+export const initiateTransfer = async (
+  authority: Keypair,
+  vin: string,
+  recipient: PublicKey,
+  expiresAt = new BN(Math.floor(Date.now() / 1000) + 60 * 60)
+) => {
+  const pendingTransfer = pendingTransferPda(carPda(vin));
+
+  await program.methods
+    .initiateTransfer(vin, recipient, expiresAt)
+    .accounts({
+      pendingTransfer,
+      car: carPda(vin),
+      authority: authority.publicKey,
+      feeConfig: feeConfigPda,
+      coOwnership: null,
+      organization: null,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([authority])
+    .rpc();
+
+  return pendingTransfer;
+};
+
+export const acceptTransfer = async (
+  initiator: PublicKey,
+  recipient: TestUser,
+  vin: string,
+  accounts = {}
+) => {
+  const car = carPda(vin);
+  const { transferCount } = await program.account.carAccount.fetch(car);
+
+  await program.methods
+    .acceptTransfer(vin)
+    .accounts({
+      pendingTransfer: pendingTransferPda(car),
+      car,
+      initiator,
+      recipient: recipient.keypair.publicKey,
+      recipientPda: recipient.pda,
+      treasury: treasuryPda,
+      taxConfig: taxConfigPda,
+      taxTreasury: taxTreasuryPda,
+      taxReceipt: taxReceiptPda(car, transferCount),
+      ownershipRecord: ownershipRecordPda(car, transferCount),
+      coOwnership: null,
+      organization: null,
+      systemProgram: SystemProgram.programId,
+      ...accounts,
+    })
+    .signers([recipient.keypair])
+    .rpc();
+
+  return transferCount;
+};
+
+// Accepts a plain sale between two users; `accounts` fills in the optional
+// accounts a consigned, co-owned or financed car needs
+export const acceptBuyRequest = async (
+  seller: TestUser,
+  buyer: TestUser,
+  vin: string,
+  { authority = seller.keypair, accounts = {}, remainingAccounts = [], signers = [] as Keypair[] } = {}
+) => {
+  const car = carPda(vin);
+  const { transferCount } = await program.account.carAccount.fetch(car);
+
+  await program.methods
+    .acceptBuyRequest(vin, buyer.keypair.publicKey)
+    .accounts({
+      buyRequest: buyRequestPda(vin, buyer.keypair.publicKey),
+      car,
+      ownerPda: seller.pda,
+      buyerPda: buyer.pda,
+      buyerOrganization: null,
+      owner: seller.keypair.publicKey,
+      authority: authority.publicKey,
+      consignment: null,
+      dealer: null,
+      dealerWallet: null,
+      coOwnership: null,
+      lien: null,
+      lienholder: null,
+      organization: null,
+      buyerAccount: buyer.keypair.publicKey,
+      feeConfig: feeConfigPda,
+      treasury: treasuryPda,
+      taxConfig: taxConfigPda,
+      taxTreasury: taxTreasuryPda,
+      taxReceipt: taxReceiptPda(car, transferCount),
+      ownershipRecord: ownershipRecordPda(car, transferCount),
+      systemProgram: SystemProgram.programId,
+      ...accounts,
+    })
+    .remainingAccounts(remainingAccounts)
+    .signers([authority, ...signers])
+    .rpc();
+
+  return transferCount;
+};
+
+export type InspectionCenterSetup = { center: PublicKey; operator: Keypair; licenceNumber: string };
+
+// Licenses a centre run by a fresh operator wallet and enrols the given inspectors
+export const registerInspectionCenter = async (
+  inspectors: TestUser[],
+  approvedInspectionTypes: object[] = INSPECTION_TYPES
+): Promise<InspectionCenterSetup> => {
+  const operator = await fundedKeypair();
+  const licenceNumber = `LIC-${randomBytes(6).toString('hex')}`;
+  const center = inspectionCenterPda(licenceNumber);
+  const addressUri = `ipfs://centre/${licenceNumber}`;
+
+  await program.methods
+    .registerInspectionCenter(
+      licenceNumber,
+      'Test Inspection Centre',
+      addressUri,
+      documentDigest(addressUri),
+      20,
+      approvedInspectionTypes
+    )
+    .accounts({
+      inspectionCenter: center,
+      operator: operator.publicKey,
+      government: governmentKeypair.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([governmentKeypair])
+    .rpc();
+
+  for (const inspector of inspectors) {
+    await program.methods
+      .updateCenterInspectors(true)
+      .accounts({ inspectionCenter: center, inspector: inspector.pda, authority: operator.publicKey })
+      .signers([operator])
+      .rpc();
+  }
+
+  return { center, operator, licenceNumber };
+};
+
+// Books an inspection with a window open for the next hour
+export const requestInspection = async (
+  owner: Keypair,
+  vin: string,
+  { inspectionType = { periodic: {} } as object, fee = new BN(0) } = {}
+) => {
+  const now = Math.floor(Date.now() / 1000);
+  const inspectionRequest = inspectionRequestPda(carPda(vin));
+
+  await program.methods
+    .requestInspection(vin, inspectionType, new BN(now - 60), new BN(now + 60 * 60), fee)
+    .accounts({
+      inspectionRequest,
+      car: carPda(vin),
+      owner: owner.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([owner])
+    .rpc();
+
+  return inspectionRequest;
+};
+
+export const claimInspection = async (inspector: TestUser, center: PublicKey, vin: string) => {
+  await program.methods
+    .claimInspection()
+    .accounts({
+      inspectionRequest: inspectionRequestPda(carPda(vin)),
+      car: carPda(vin),
+      inspector: inspector.pda,
+      inspectorSigner: inspector.keypair.publicKey,
+      inspectorStats: inspectorStatsPda(inspector.pda),
+      inspectionCenter: center,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([inspector.keypair])
+    .rpc();
+};
+
+// Issues the report answering a claimed booking; returns the report address
+export const issueCarReport = async (
+  inspector: TestUser,
+  center: PublicKey,
+  requester: PublicKey,
+  vin: string,
+  {
+    reportId = new BN(Date.now()),
+    inspectionType = { periodic: {} } as object,
+    passed = true,
+    checklist = passingChecklist,
+  } = {}
+) => {
+  const car = carPda(vin);
+  const report = carReportPda(car, inspector.keypair.publicKey, reportId);
+  const fullReportUri = `ipfs://report/${vin}/${reportId.toString()}`;
+
+  await program.methods
+    .issueCarReport(
+      reportId,
+      vin,
+      inspectionType,
+      passed,
+      8,
+      8,
+      7,
+      checklist,
+      fullReportUri,
+      documentDigest(fullReportUri, 'application/pdf'),
+      passed ? 'Roadworthy' : 'Not roadworthy',
+      ''
+    )
+    .accounts({
+      carReport: report,
+      car,
+      inspector: inspector.pda,
+      inspectorSigner: inspector.keypair.publicKey,
+      inspectorStats: inspectorStatsPda(inspector.pda),
+      inspectionCenter: center,
+      inspectionRequest: inspectionRequestPda(car),
+      requester,
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([inspector.keypair])
+    .rpc();
+
+  return { report, reportId };
+};
+
+// The whole booking flow: request, claim and issue a report on the owner's car
+export const inspectCar = async (
+  owner: Keypair,
+  inspector: TestUser,
+  center: PublicKey,
+  vin: string,
+  options: {
+    inspectionType?: object;
+    passed?: boolean;
+    checklist?: typeof passingChecklist;
+    fee?: BN;
+    reportId?: BN;
+  } = {}
+) => {
+  await requestInspection(owner, vin, options);
+  await claimInspection(inspector, center, vin);
+  return issueCarReport(inspector, center, owner.publicKey, vin, options);
+};
+
+export const acceptCarReport = async (
+  owner: Keypair,
+  vin: string,
+  inspector: TestUser,
+  reportId: BN
+) => {
+  const car = carPda(vin);
+
+  await program.methods
+    .acceptReport(reportId, vin, inspector.keypair.publicKey)
+    .accounts({
+      report: carReportPda(car, inspector.keypair.publicKey, reportId),
+      car,
+      owner: owner.publicKey,
+      inspectionConfig: inspectionConfigPda,
+      inspectorStats: inspectorStatsPda(inspector.pda),
+    })
+    .signers([owner])
+    .rpc();
+};
@@ -0,0 +1,96 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  FEES,
+  TestUser,
+  acceptBuyRequest,
+  balance,
+  ensureConfigs,
+  feeConfigPda,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  setForSale,
+  taxConfigPda,
+  taxReceiptPda,
+  taxTreasuryPda,
+  treasuryPda,
+} from './helpers';
+
+describe('📜 Car Chain - Ownership History', () => {
+  const price = new BN(2 * LAMPORTS_PER_SOL);
+
+  let firstOwner: TestUser;
+  let secondOwner: TestUser;
+  let thirdOwner: TestUser;
+  let vin: string;
+  let car: PublicKey;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    firstOwner = await registerVerifiedUser();
+    secondOwner = await registerVerifiedUser();
+    thirdOwner = await registerVerifiedUser();
+    ({ vin, car } = await registerCar(firstOwner.keypair));
+  }, 60000);
+
+  it('✅ Should record a sale as the first link of the chain', async () => {
+    await setForSale(firstOwner.keypair, vin, price);
+    await requestBuy(secondOwner, vin);
+    const transferIndex = await acceptBuyRequest(firstOwner, secondOwner, vin);
+
+    expect(transferIndex).toBe(0);
+    const record = await program.account.ownershipRecord.fetch(ownershipRecordPda(car, 0));
+    expect(record.car.toString()).toBe(car.toString());
+    expect(record.transferIndex).toBe(0);
+    expect(record.previousOwner.toString()).toBe(firstOwner.keypair.publicKey.toString());
+    expect(record.newOwner.toString()).toBe(secondOwner.keypair.publicKey.toString());
+    expect(record.price?.toString()).toBe(price.toString());
+    expect(record.mechanism).toHaveProperty('sale');
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.transferCount).toBe(1);
+  }, 30000);
+
+  it('✅ Should chain a direct transfer signed by both parties onto the sale', async () => {
+    const treasuryBefore = await balance(treasuryPda);
+
+    await program.methods
+      .transferCar(vin, thirdOwner.userName)
+      .accounts({
+        car,
+        authority: secondOwner.keypair.publicKey,
+        newOwner: thirdOwner.keypair.publicKey,
+        newOwnerPda: thirdOwner.pda,
+        feeConfig: feeConfigPda,
+        treasury: treasuryPda,
+        taxConfig: taxConfigPda,
+        taxTreasury: taxTreasuryPda,
+        taxReceipt: taxReceiptPda(car, 1),
+        ownershipRecord: ownershipRecordPda(car, 1),
+        coOwnership: null,
+        organization: null,
+        newOwnerOrganization: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([secondOwner.keypair, thirdOwner.keypair])
+      .rpc();
+
+    expect(await balance(treasuryPda)).toBe(treasuryBefore + FEES.transferFee.toNumber());
+
+    const first = await program.account.ownershipRecord.fetch(ownershipRecordPda(car, 0));
+    const second = await program.account.ownershipRecord.fetch(ownershipRecordPda(car, 1));
+    expect(second.transferIndex).toBe(1);
+    expect(second.previousOwner.toString()).toBe(first.newOwner.toString());
+    expect(second.newOwner.toString()).toBe(thirdOwner.keypair.publicKey.toString());
+    expect(second.price).toBeNull();
+    expect(second.mechanism).toHaveProperty('directTransfer');
+    expect(second.timestamp.gte(first.timestamp)).toBe(true);
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.owner.toString()).toBe(thirdOwner.keypair.publicKey.toString());
+    expect(carAccount.transferCount).toBe(2);
+  }, 15000);
+});
@@ -0,0 +1,154 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  TestUser,
+  balance,
+  carPda,
+  carReportPda,
+  claimInspection,
+  ensureConfigs,
+  acceptTransfer,
+  expectAnchorError,
+  initiateTransfer,
+  inspectionRequestPda,
+  inspectorStatsPda,
+  issueCarReport,
+  program,
+  registerCar,
+  registerInspectionCenter,
+  registerVerifiedUser,
+  requestInspection,
+} from './helpers';
+
+describe('🗓️ Car Chain - Inspection Booking', () => {
+  const fee = new BN(0.1 * LAMPORTS_PER_SOL);
+
+  let owner: TestUser;
+  let stranger: TestUser;
+  let newOwner: TestUser;
+  let inspector: TestUser;
+  let otherInspector: TestUser;
+  let center: PublicKey;
+
+  const cancelRequest = (vin: string, authority = owner) =>
+    program.methods
+      .cancelInspectionRequest()
+      .accounts({
+        inspectionRequest: inspectionRequestPda(carPda(vin)),
+        car: carPda(vin),
+        owner: owner.keypair.publicKey,
+        authority: authority.keypair.publicKey,
+      })
+      .signers([authority.keypair])
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    stranger = await registerVerifiedUser();
+    newOwner = await registerVerifiedUser();
+    inspector = await registerVerifiedUser({ inspector: {} });
+    otherInspector = await registerVerifiedUser({ inspector: {} });
+    ({ center } = await registerInspectionCenter([inspector, otherInspector]));
+  }, 60000);
+
+  it('❌ Should only let the owner book an inspection', async () => {
+    const { vin } = await registerCar(owner.keypair);
+
+    await expectAnchorError(requestInspection(stranger.keypair, vin, { fee }), 'NotCarOwner');
+  }, 15000);
+
+  it('✅ Should refund the escrowed fee when an open booking is cancelled', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+    const ownerBefore = await balance(owner.keypair.publicKey);
+
+    const inspectionRequest = await requestInspection(owner.keypair, vin, { fee });
+    const escrowed = await balance(inspectionRequest);
+    expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore - escrowed);
+
+    const request = await program.account.inspectionRequest.fetch(inspectionRequest);
+    expect(request.car.toString()).toBe(car.toString());
+    expect(request.fee.toString()).toBe(fee.toString());
+    expect(request.status).toHaveProperty('open');
+
+    await cancelRequest(vin);
+    expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore);
+  }, 15000);
+
+  it('✅ Should release the fee to the inspector who answers the booking', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+    const inspectionRequest = await requestInspection(owner.keypair, vin, { fee });
+    await claimInspection(inspector, center, vin);
+
+    const request = await program.account.inspectionRequest.fetch(inspectionRequest);
+    expect(request.status).toHaveProperty('claimed');
+    expect(request.inspector?.toString()).toBe(inspector.keypair.publicKey.toString());
+
+    await expectAnchorError(cancelRequest(vin), 'CannotCancelInspection');
+    await expectAnchorError(
+      issueCarReport(otherInspector, center, owner.keypair.publicKey, vin),
+      'NotAssignedInspector'
+    );
+
+    const requestRent = (await balance(inspectionRequest)) - fee.toNumber();
+    const ownerBefore = await balance(owner.keypair.publicKey);
+    const inspectorBefore = await balance(inspector.keypair.publicKey);
+
+    const reportId = new BN(Date.now());
+    await issueCarReport(inspector, center, owner.keypair.publicKey, vin, { reportId });
+
+    // The inspector pays the rent of the report and of their first stats account
+    const rentPaid =
+      (await balance(carReportPda(car, inspector.keypair.publicKey, reportId))) +
+      (await balance(inspectorStatsPda(inspector.pda)));
+    expect(await balance(inspector.keypair.publicKey)).toBe(
+      inspectorBefore + fee.toNumber() - rentPaid
+    );
+    expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore + requestRent);
+    expect(await program.account.inspectionRequest.fetchNullable(inspectionRequest)).toBeNull();
+  }, 30000);
+
+  it('❌ Should not pay out a claimed booking once the car has changed hands', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    await requestInspection(owner.keypair, vin, { fee });
+    await claimInspection(inspector, center, vin);
+    await initiateTransfer(owner.keypair, vin, newOwner.keypair.publicKey);
+    await acceptTransfer(owner.keypair.publicKey, newOwner, vin);
+
+    await expectAnchorError(
+      issueCarReport(inspector, center, owner.keypair.publicKey, vin),
+      'StaleInspectionRequest'
+    );
+    await cancelRequest(vin, newOwner);
+  }, 60000);
+
+  describe('🔑 Booking left behind by a previous owner', () => {
+    let vin: string;
+    let inspectionRequest: PublicKey;
+
+    beforeAll(async () => {
+      ({ vin } = await registerCar(owner.keypair));
+      inspectionRequest = await requestInspection(owner.keypair, vin, { fee });
+      await initiateTransfer(owner.keypair, vin, newOwner.keypair.publicKey);
+      await acceptTransfer(owner.keypair.publicKey, newOwner, vin);
+    }, 60000);
+
+    it('❌ Should not let an inspector claim it', async () => {
+      await expectAnchorError(claimInspection(inspector, center, vin), 'StaleInspectionRequest');
+    }, 15000);
+
+    it('✅ Should let the new owner clear it and refund the previous owner', async () => {
+      const escrowed = await balance(inspectionRequest);
+      const ownerBefore = await balance(owner.keypair.publicKey);
+
+      await cancelRequest(vin, newOwner);
+
+      expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore + escrowed);
+      expect(await program.account.inspectionRequest.fetchNullable(inspectionRequest)).toBeNull();
+
+      await requestInspection(newOwner.keypair, vin, { fee });
+      const request = await program.account.inspectionRequest.fetch(inspectionRequest);
+      expect(request.owner.toString()).toBe(newOwner.keypair.publicKey.toString());
+    }, 30000);
+  });
+});
@@ -0,0 +1,144 @@
+import { SystemProgram } from '@solana/web3.js';
+import { randomBytes } from 'crypto';
+import {
+  InspectionCenterSetup,
+  TestUser,
+  carPda,
+  claimInspection,
+  documentDigest,
+  ensureConfigs,
+  expectAnchorError,
+  governmentKeypair,
+  inspectCar,
+  inspectionCenterPda,
+  inspectionRequestPda,
+  program,
+  registerCar,
+  registerInspectionCenter,
+  registerVerifiedUser,
+  requestInspection,
+} from './helpers';
+
+describe('🏭 Car Chain - Inspection Centres', () => {
+  let owner: TestUser;
+  let inspector: TestUser;
+  let outsider: TestUser;
+  let setup: InspectionCenterSetup;
+
+  const updateInspectors = (inspector: TestUser, add: boolean, authority = setup.operator) =>
+    program.methods
+      .updateCenterInspectors(add)
+      .accounts({
+        inspectionCenter: setup.center,
+        inspector: inspector.pda,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+  const updateLicence = (licenceStatus: { active: {} } | { suspended: {} }) =>
+    program.methods
+      .updateInspectionCenter(20, [{ periodic: {} }], licenceStatus)
+      .accounts({ inspectionCenter: setup.center, government: governmentKeypair.publicKey })
+      .signers([governmentKeypair])
+      .rpc();
+
+  // The centre refuses the claim, so the booking stays open for another inspector
+  const expectClaimRefused = async (vin: string, claimant: TestUser, errorName: string) => {
+    await expectAnchorError(claimInspection(claimant, setup.center, vin), errorName);
+    const request = await program.account.inspectionRequest.fetch(inspectionRequestPda(carPda(vin)));
+    expect(request.status).toHaveProperty('open');
+  };
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    inspector = await registerVerifiedUser({ inspector: {} });
+    outsider = await registerVerifiedUser({ inspector: {} });
+  }, 60000);
+
+  it('❌ Should only let the government license a centre', async () => {
+    const licenceNumber = `LIC-${randomBytes(6).toString('hex')}`;
+    const addressUri = 'ipfs://QmUnlicensedGarage';
+
+    await expectAnchorError(
+      program.methods
+        .registerInspectionCenter(
+          licenceNumber,
+          'Unlicensed Garage',
+          addressUri,
+          documentDigest(addressUri),
+          5,
+          [{ periodic: {} }]
+        )
+        .accounts({
+          inspectionCenter: inspectionCenterPda(licenceNumber),
+          operator: owner.keypair.publicKey,
+          government: owner.keypair.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([owner.keypair])
+        .rpc(),
+      'UnauthorizedVerifier'
+    );
+  }, 15000);
+
+  it('✅ Should register a centre and let its operator enrol inspectors', async () => {
+    setup = await registerInspectionCenter([], [{ periodic: {} }]);
+
+    await expectAnchorError(updateInspectors(inspector, true, owner.keypair), 'ConstraintHasOne');
+    await expectAnchorError(updateInspectors(owner, true), 'NotInspector');
+    await updateInspectors(inspector, true);
+    await expectAnchorError(updateInspectors(inspector, true), 'InspectorAlreadyMember');
+
+    const center = await program.account.inspectionCenter.fetch(setup.center);
+    expect(center.authority.toString()).toBe(setup.operator.publicKey.toString());
+    expect(center.licenceNumber).toBe(setup.licenceNumber);
+    expect(center.capacity).toBe(20);
+    expect(center.approvedInspectionTypes).toEqual([{ periodic: {} }]);
+    expect(center.licenceStatus).toHaveProperty('active');
+    expect(center.inspectors.map((key) => key.toString())).toEqual([inspector.pda.toString()]);
+  }, 30000);
+
+  it('✅ Should record the issuing centre on the report', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    const { report } = await inspectCar(owner.keypair, inspector, setup.center, vin);
+
+    const reportAccount = await program.account.carReport.fetch(report);
+    expect(reportAccount.inspectionCenter.toString()).toBe(setup.center.toString());
+  }, 30000);
+
+  it('❌ Should refuse bookings for inspection types the centre is not approved for', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    await requestInspection(owner.keypair, vin, { inspectionType: { preSale: {} } });
+
+    await expectClaimRefused(vin, inspector, 'InspectionTypeNotApproved');
+  }, 30000);
+
+  it('❌ Should refuse bookings from inspectors outside the centre', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    await requestInspection(owner.keypair, vin);
+
+    await expectClaimRefused(vin, outsider, 'InspectorNotMember');
+  }, 30000);
+
+  it('❌ Should refuse bookings while the licence is suspended', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    await requestInspection(owner.keypair, vin);
+    await updateLicence({ suspended: {} });
+
+    await expectClaimRefused(vin, inspector, 'CenterLicenceSuspended');
+
+    await updateLicence({ active: {} });
+    const center = await program.account.inspectionCenter.fetch(setup.center);
+    expect(center.licenceStatus).toHaveProperty('active');
+  }, 30000);
+
+  it('✅ Should let the operator remove an inspector', async () => {
+    await updateInspectors(inspector, false);
+
+    const center = await program.account.inspectionCenter.fetch(setup.center);
+    expect(center.inspectors).toHaveLength(0);
+    await expectAnchorError(updateInspectors(inspector, false), 'InspectorNotMember');
+  }, 15000);
+});
@@ -0,0 +1,85 @@
+import { BN } from '@coral-xyz/anchor';
+import { PublicKey } from '@solana/web3.js';
+import {
+  TestUser,
+  claimInspection,
+  ensureConfigs,
+  expectAnchorError,
+  issueCarReport,
+  passingChecklist,
+  program,
+  registerCar,
+  registerInspectionCenter,
+  registerVerifiedUser,
+  requestInspection,
+} from './helpers';
+
+// Two bits per item, in ChecklistItem order
+const PASS = 1;
+const ADVISORY = 2;
+const FAIL = 3;
+const TYRES = 1;
+const EMISSIONS = 3;
+
+const withResult = (results: number, item: number, result: number) =>
+  (results & ~(0b11 << (item * 2))) | (result << (item * 2));
+
+describe('📋 Car Chain - Inspection Checklist', () => {
+  let owner: TestUser;
+  let inspector: TestUser;
+  let center: PublicKey;
+  let vin: string;
+
+  const issue = (checklist: typeof passingChecklist, passed: boolean) =>
+    issueCarReport(inspector, center, owner.keypair.publicKey, vin, {
+      reportId: new BN(Date.now()),
+      checklist,
+      passed,
+    });
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    inspector = await registerVerifiedUser({ inspector: {} });
+    ({ center } = await registerInspectionCenter([inspector]));
+    ({ vin } = await registerCar(owner.keypair));
+    // Rejected reports leave the claimed booking open for the next attempt
+    await requestInspection(owner.keypair, vin);
+    await claimInspection(inspector, center, vin);
+  }, 60000);
+
+  it('❌ Should reject results beyond the last checklist item', async () => {
+    await expectAnchorError(
+      issue({ ...passingChecklist, results: passingChecklist.results | (PASS << 24) }, true),
+      'InvalidChecklist'
+    );
+  }, 15000);
+
+  it('❌ Should reject an efficiency above 100%', async () => {
+    await expectAnchorError(issue({ ...passingChecklist, brakeEfficiency: 101 }, true), 'InvalidChecklist');
+  }, 15000);
+
+  it('❌ Should not pass a car with a failed item', async () => {
+    const results = withResult(passingChecklist.results, EMISSIONS, FAIL);
+
+    await expectAnchorError(issue({ ...passingChecklist, results }, true), 'ChecklistContradictsVerdict');
+  }, 15000);
+
+  it('✅ Should store the checklist readable straight from the account', async () => {
+    const checklist = {
+      ...passingChecklist,
+      results: withResult(withResult(passingChecklist.results, TYRES, ADVISORY), EMISSIONS, FAIL),
+      tyreTreadDepth: [32, 31, 18, 17],
+      coEmissions: 450,
+    };
+
+    const { report } = await issue(checklist, false);
+
+    const reportAccount = await program.account.carReport.fetch(report);
+    expect(reportAccount.passed).toBe(false);
+    expect(reportAccount.checklist).toEqual(checklist);
+    const { results } = reportAccount.checklist;
+    expect((results >> (TYRES * 2)) & 0b11).toBe(ADVISORY);
+    expect((results >> (EMISSIONS * 2)) & 0b11).toBe(FAIL);
+  }, 15000);
+});
@@ -0,0 +1,98 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  INSPECTION,
+  TestUser,
+  acceptCarReport,
+  ensureConfigs,
+  expectAnchorError,
+  governmentKeypair,
+  inspectCar,
+  inspectionConfigPda,
+  program,
+  provider,
+  registerCar,
+  registerInspectionCenter,
+  registerVerifiedUser,
+  setForSale,
+  sleep,
+} from './helpers';
+
+describe('⏰ Car Chain - Inspection Expiry', () => {
+  const price = new BN(LAMPORTS_PER_SOL);
+
+  let owner: TestUser;
+  let inspector: TestUser;
+  let center: PublicKey;
+  let heavyVin: string;
+  let heavyCar: PublicKey;
+  let privateVin: string;
+  let privateCar: PublicKey;
+
+  const refresh = (...cars: PublicKey[]) =>
+    program.methods
+      .refreshInspectionStatus()
+      .accounts({ cranker: provider.wallet.publicKey })
+      .remainingAccounts(cars.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    inspector = await registerVerifiedUser({ inspector: {} });
+    ({ center } = await registerInspectionCenter([inspector]));
+    ({ vin: heavyVin, car: heavyCar } = await registerCar(owner.keypair, { category: { heavy: {} } }));
+    ({ vin: privateVin, car: privateCar } = await registerCar(owner.keypair));
+  }, 60000);
+
+  afterAll(async () => {
+    await ensureConfigs();
+  }, 30000);
+
+  it('✅ Should set the expiry from the validity rule of the car category', async () => {
+    const heavy = await inspectCar(owner.keypair, inspector, center, heavyVin);
+    await acceptCarReport(owner.keypair, heavyVin, inspector, heavy.reportId);
+    const { reportDate } = await program.account.carReport.fetch(heavy.report);
+
+    const carAccount = await program.account.carAccount.fetch(heavyCar);
+    expect(carAccount.inspectionExpiresAt?.toString()).toBe(
+      reportDate.add(INSPECTION.validityRules[0].validitySeconds).toString()
+    );
+
+    const passenger = await inspectCar(owner.keypair, inspector, center, privateVin);
+    await acceptCarReport(owner.keypair, privateVin, inspector, passenger.reportId);
+  }, 30000);
+
+  it('✅ Should let anyone mark lapsed inspections as expired', async () => {
+    await sleep(6);
+    await refresh(heavyCar, privateCar);
+
+    const heavy = await program.account.carAccount.fetch(heavyCar);
+    expect(heavy.inspectionStatus).toHaveProperty('expired');
+    const passenger = await program.account.carAccount.fetch(privateCar);
+    expect(passenger.inspectionStatus).toHaveProperty('passed');
+  }, 30000);
+
+  it('❌ Should refuse listings without a valid inspection when required', async () => {
+    await program.methods
+      .setInspectionConfig(
+        INSPECTION.validityRules,
+        INSPECTION.defaultValiditySeconds,
+        true,
+        INSPECTION.maxVoidedReports
+      )
+      .accounts({
+        inspectionConfig: inspectionConfigPda,
+        government: governmentKeypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([governmentKeypair])
+      .rpc();
+
+    await expectAnchorError(setForSale(owner.keypair, heavyVin, price), 'ValidInspectionRequired');
+    await setForSale(owner.keypair, privateVin, price);
+
+    const passenger = await program.account.carAccount.fetch(privateCar);
+    expect(passenger.isForSale).toBe(true);
+  }, 30000);
+});
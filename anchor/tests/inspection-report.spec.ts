@@ -0,0 +1,143 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  INSPECTION,
+  TestUser,
+  acceptCarReport,
+  carPda,
+  claimInspection,
+  ensureConfigs,
+  expectAnchorError,
+  inspectCar,
+  inspectionConfigPda,
+  inspectorStatsPda,
+  issueCarReport,
+  program,
+  registerCar,
+  registerInspectionCenter,
+  registerVerifiedUser,
+  requestInspection,
+} from './helpers';
+
+describe('🔍 Car Chain - Inspection Reports', () => {
+  let owner: TestUser;
+  let inspector: TestUser;
+  let center: PublicKey;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    inspector = await registerVerifiedUser({ inspector: {} });
+    ({ center } = await registerInspectionCenter([inspector]));
+  }, 60000);
+
+  it('✅ Should leave the car untouched until the owner accepts', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+    const { report } = await inspectCar(owner.keypair, inspector, center, vin, {
+      inspectionType: { preSale: {} },
+    });
+
+    const reportAccount = await program.account.carReport.fetch(report);
+    expect(reportAccount.inspectionType).toHaveProperty('preSale');
+    expect(reportAccount.passed).toBe(true);
+    expect(reportAccount.status).toHaveProperty('pending');
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.inspectionStatus).toHaveProperty('pending');
+    expect(carAccount.lastInspectionDate).toBeNull();
+    expect(carAccount.latestInspectionReport).toBeNull();
+  }, 30000);
+
+  it('✅ Should apply an accepted periodic report to the car', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+    const { report, reportId } = await inspectCar(owner.keypair, inspector, center, vin);
+
+    await acceptCarReport(owner.keypair, vin, inspector, reportId);
+
+    const reportAccount = await program.account.carReport.fetch(report);
+    expect(reportAccount.status).toHaveProperty('accepted');
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.inspectionStatus).toHaveProperty('passed');
+    expect(carAccount.lastInspectionDate?.toString()).toBe(reportAccount.reportDate.toString());
+    expect(carAccount.latestInspectionReport).toBe(report.toString());
+    expect(carAccount.inspectionExpiresAt?.toString()).toBe(
+      reportAccount.reportDate.add(INSPECTION.defaultValiditySeconds).toString()
+    );
+  }, 30000);
+
+  it('✅ Should mark the car failed on an accepted failing report', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+    const { reportId } = await inspectCar(owner.keypair, inspector, center, vin, { passed: false });
+
+    await acceptCarReport(owner.keypair, vin, inspector, reportId);
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.inspectionStatus).toHaveProperty('failed');
+    expect(carAccount.inspectionExpiresAt).toBeNull();
+  }, 30000);
+
+  it('❌ Should not answer a booking with a report of another type', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    await requestInspection(owner.keypair, vin, { inspectionType: { periodic: {} } });
+    await claimInspection(inspector, center, vin);
+
+    await expectAnchorError(
+      issueCarReport(inspector, center, owner.keypair.publicKey, vin, {
+        reportId: new BN(Date.now()),
+        inspectionType: { postAccident: {} },
+      }),
+      'InspectionTypeMismatch'
+    );
+  }, 30000);
+
+  describe('🔒 Accepting a report', () => {
+    let vin: string;
+    let report: PublicKey;
+    let reportId: BN;
+
+    const accept = (
+      signer: Keypair,
+      { id = reportId, carVin = vin, issuer = inspector.keypair.publicKey } = {}
+    ) =>
+      program.methods
+        .acceptReport(id, carVin, issuer)
+        .accounts({
+          report,
+          car: carPda(carVin),
+          owner: signer.publicKey,
+          inspectionConfig: inspectionConfigPda,
+          inspectorStats: inspectorStatsPda(inspector.pda),
+        })
+        .signers([signer])
+        .rpc();
+
+    beforeAll(async () => {
+      ({ vin } = await registerCar(owner.keypair));
+      ({ report, reportId } = await inspectCar(owner.keypair, inspector, center, vin));
+    }, 60000);
+
+    it('❌ Should reject acceptance by someone other than the car owner', async () => {
+      await expectAnchorError(accept(Keypair.generate()), 'NotCarOwner');
+    }, 15000);
+
+    it('❌ Should reject a report that was not issued by the given issuer', async () => {
+      await expectAnchorError(
+        accept(owner.keypair, { issuer: owner.keypair.publicKey }),
+        'ConstraintSeeds'
+      );
+    }, 15000);
+
+    it('❌ Should reject a report presented under another report id', async () => {
+      await expectAnchorError(accept(owner.keypair, { id: reportId.addn(1) }), 'ConstraintSeeds');
+    }, 15000);
+
+    it('❌ Should reject a report presented against another car', async () => {
+      const { vin: otherVin } = await registerCar(owner.keypair);
+
+      await expectAnchorError(accept(owner.keypair, { carVin: otherVin }), 'ConstraintSeeds');
+
+      const reportAccount = await program.account.carReport.fetch(report);
+      expect(reportAccount.status).toHaveProperty('pending');
+    }, 15000);
+  });
+});
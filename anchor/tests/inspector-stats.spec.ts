@@ -0,0 +1,143 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  INSPECTION,
+  TestUser,
+  acceptCarReport,
+  balance,
+  carPda,
+  claimInspection,
+  documentDigest,
+  ensureConfigs,
+  expectAnchorError,
+  findPda,
+  governmentKeypair,
+  inspectCar,
+  inspectionConfigPda,
+  inspectorStatsPda,
+  program,
+  registerCar,
+  registerInspectionCenter,
+  registerVerifiedUser,
+  requestInspection,
+} from './helpers';
+
+describe('📊 Car Chain - Inspector Performance', () => {
+  const evidenceUri = 'ipfs://QmSecondOpinion';
+  const fee = new BN(0.05 * LAMPORTS_PER_SOL);
+
+  let owner: TestUser;
+  let inspector: TestUser;
+  let center: PublicKey;
+  let stats: PublicKey;
+
+  const disputePda = (report: PublicKey) => findPda(Buffer.from('report_dispute'), report.toBuffer());
+
+  const disputeAndVoid = async (vin: string, report: PublicKey) => {
+    await program.methods
+      .disputeReport(
+        report,
+        'Odometer misread',
+        evidenceUri,
+        documentDigest(evidenceUri, 'application/pdf')
+      )
+      .accounts({
+        carReport: report,
+        conformityReport: null,
+        reportDispute: disputePda(report),
+        car: carPda(vin),
+        owner: owner.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([owner.keypair])
+      .rpc();
+
+    await program.methods
+      .resolveDispute({ voided: {} })
+      .accounts({
+        reportDispute: disputePda(report),
+        carReport: report,
+        conformityReport: null,
+        inspectorStats: stats,
+        car: carPda(vin),
+        inspectionConfig: inspectionConfigPda,
+        government: governmentKeypair.publicKey,
+      })
+      .signers([governmentKeypair])
+      .rpc();
+  };
+
+  const reinstate = (government = governmentKeypair) =>
+    program.methods
+      .reinstateInspector()
+      .accounts({ inspectorStats: stats, government: government.publicKey })
+      .signers([government])
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    inspector = await registerVerifiedUser({ inspector: {} });
+    ({ center } = await registerInspectionCenter([inspector]));
+    stats = inspectorStatsPda(inspector.pda);
+  }, 60000);
+
+  it('✅ Should count issued and accepted reports', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    const { reportId } = await inspectCar(owner.keypair, inspector, center, vin);
+
+    let account = await program.account.inspectorStats.fetch(stats);
+    expect(account.inspector.toString()).toBe(inspector.pda.toString());
+    expect(account.reportsIssued).toBe(1);
+    expect(account.reportsAccepted).toBe(0);
+
+    await acceptCarReport(owner.keypair, vin, inspector, reportId);
+
+    account = await program.account.inspectorStats.fetch(stats);
+    expect(account.reportsAccepted).toBe(1);
+    expect(account.suspendedAt).toBeNull();
+  }, 30000);
+
+  it('✅ Should suspend the inspector once a voided report reaches the threshold', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    const { report } = await inspectCar(owner.keypair, inspector, center, vin);
+
+    await disputeAndVoid(vin, report);
+
+    const voided = await program.account.carReport.fetch(report);
+    expect(voided.status).toHaveProperty('voided');
+    const account = await program.account.inspectorStats.fetch(stats);
+    expect(account.reportsIssued).toBe(2);
+    expect(account.reportsDisputed).toBe(1);
+    expect(account.reportsVoided).toBe(INSPECTION.maxVoidedReports);
+    expect(account.suspendedAt).not.toBeNull();
+  }, 30000);
+
+  it('❌ Should refuse bookings from a suspended inspector and keep the fee in escrow', async () => {
+    const { vin } = await registerCar(owner.keypair);
+    const ownerBefore = await balance(owner.keypair.publicKey);
+
+    const inspectionRequest = await requestInspection(owner.keypair, vin, { fee });
+    await expectAnchorError(claimInspection(inspector, center, vin), 'InspectorSuspended');
+
+    // The booking stays open for another inspector, or for cancellation
+    const escrowed = await balance(inspectionRequest);
+    expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore - escrowed);
+    const request = await program.account.inspectionRequest.fetch(inspectionRequest);
+    expect(request.status).toHaveProperty('open');
+    expect(request.inspector).toBeNull();
+    expect(request.fee.toString()).toBe(fee.toString());
+  }, 30000);
+
+  it('✅ Should let only the government reinstate a suspended inspector', async () => {
+    await expectAnchorError(reinstate(owner.keypair), 'UnauthorizedVerifier');
+    await reinstate();
+
+    const account = await program.account.inspectorStats.fetch(stats);
+    expect(account.suspendedAt).toBeNull();
+    // The voided count is kept
+    expect(account.reportsVoided).toBe(INSPECTION.maxVoidedReports);
+
+    await expectAnchorError(reinstate(), 'InspectorNotSuspended');
+  }, 15000);
+});
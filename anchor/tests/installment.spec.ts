@@ -0,0 +1,267 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  FEES,
+  TestUser,
+  balance,
+  carPda,
+  ensureConfigs,
+  expectAnchorError,
+  feeConfigPda,
+  findPda,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  sleep,
+  treasuryPda,
+} from './helpers';
+
+describe('📆 Car Chain - Instalment Plans', () => {
+  const downPayment = new BN(0.5 * LAMPORTS_PER_SOL);
+  const installmentAmount = new BN(0.25 * LAMPORTS_PER_SOL);
+  const installmentCount = 2;
+  const refundBps = 5000;
+
+  let seller: TestUser;
+  let buyer: TestUser;
+  let stranger: TestUser;
+
+  const planPda = (car: PublicKey) => findPda(Buffer.from('installment_plan'), car.toBuffer());
+
+  const proposePlan = (vin: string, car: PublicKey, intervalSeconds: number, graceSeconds: number) =>
+    program.methods
+      .proposeInstallmentPlan(vin, buyer.keypair.publicKey, {
+        downPayment,
+        installmentAmount,
+        installmentCount,
+        intervalSeconds: new BN(intervalSeconds),
+        gracePeriodSeconds: new BN(graceSeconds),
+        defaultRefundBps: refundBps,
+      })
+      .accounts({
+        installmentPlan: planPda(car),
+        car,
+        seller: seller.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([seller.keypair])
+      .rpc();
+
+  const acceptPlan = (vin: string, car: PublicKey, party = buyer) =>
+    program.methods
+      .acceptInstallmentPlan(vin)
+      .accounts({
+        installmentPlan: planPda(car),
+        car,
+        buyer: party.keypair.publicKey,
+        buyerPda: party.pda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([party.keypair])
+      .rpc();
+
+  const payInstallment = (vin: string, car: PublicKey) =>
+    program.methods
+      .payInstallment(vin)
+      .accounts({
+        installmentPlan: planPda(car),
+        car,
+        buyer: buyer.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer.keypair])
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    seller = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    stranger = await registerVerifiedUser();
+  }, 60000);
+
+  it('✅ Should escrow the down payment and every instalment', async () => {
+    const { vin, car } = await registerCar(seller.keypair);
+    await proposePlan(vin, car, 3600, 0);
+
+    await expectAnchorError(acceptPlan(vin, car, stranger), 'ConstraintHasOne');
+
+    const planRent = await balance(planPda(car));
+    const buyerBefore = await balance(buyer.keypair.publicKey);
+    await acceptPlan(vin, car);
+    expect(await balance(planPda(car))).toBe(planRent + downPayment.toNumber());
+
+    await payInstallment(vin, car);
+    await payInstallment(vin, car);
+    await expectAnchorError(payInstallment(vin, car), 'PlanFullyPaid');
+
+    const totalPrice = downPayment.add(installmentAmount.muln(installmentCount));
+    expect(await balance(buyer.keypair.publicKey)).toBe(buyerBefore - totalPrice.toNumber());
+    const plan = await program.account.installmentPlan.fetch(planPda(car));
+    expect(plan.status).toHaveProperty('active');
+    expect(plan.installmentsPaid).toBe(installmentCount);
+    expect(plan.amountPaid.toString()).toBe(totalPrice.toString());
+
+    // Title has not passed yet
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.owner.toString()).toBe(seller.keypair.publicKey.toString());
+  }, 30000);
+
+  it('✅ Should release the escrow to the seller and the title to the buyer on completion', async () => {
+    const { vin, car } = await registerCar(seller.keypair);
+    await proposePlan(vin, car, 3600, 0);
+    await acceptPlan(vin, car);
+    await payInstallment(vin, car);
+    await payInstallment(vin, car);
+
+    const totalPrice = downPayment.add(installmentAmount.muln(installmentCount));
+    const saleFee = totalPrice.muln(FEES.saleFeeBps).divn(10000);
+    const planRent = (await balance(planPda(car))) - totalPrice.toNumber();
+    const sellerBefore = await balance(seller.keypair.publicKey);
+    const treasuryBefore = await balance(treasuryPda);
+
+    await program.methods
+      .completeInstallmentPlan(vin)
+      .accounts({
+        installmentPlan: planPda(car),
+        car,
+        buyer: buyer.keypair.publicKey,
+        seller: seller.keypair.publicKey,
+        feeConfig: feeConfigPda,
+        treasury: treasuryPda,
+        ownershipRecord: ownershipRecordPda(car, 0),
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer.keypair])
+      .rpc();
+
+    expect(await balance(seller.keypair.publicKey)).toBe(
+      sellerBefore + totalPrice.sub(saleFee).toNumber() + planRent
+    );
+    expect(await balance(treasuryPda)).toBe(treasuryBefore + saleFee.toNumber());
+    expect(await program.account.installmentPlan.fetchNullable(planPda(car))).toBeNull();
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.owner.toString()).toBe(buyer.keypair.publicKey.toString());
+    expect(carAccount.installmentPlan).toBeNull();
+    const record = await program.account.ownershipRecord.fetch(ownershipRecordPda(car, 0));
+    expect(record.mechanism).toHaveProperty('installment');
+    expect(record.price?.toString()).toBe(totalPrice.toString());
+  }, 30000);
+
+  it('✅ Should split the escrow by the refund rate when the buyer defaults', async () => {
+    const { vin, car } = await registerCar(seller.keypair);
+    await proposePlan(vin, car, 1, 1);
+    await acceptPlan(vin, car);
+
+    const claimDefault = () =>
+      program.methods
+        .claimInstallmentDefault(vin)
+        .accounts({
+          installmentPlan: planPda(car),
+          car,
+          seller: seller.keypair.publicKey,
+          buyer: buyer.keypair.publicKey,
+        })
+        .signers([seller.keypair])
+        .rpc();
+
+    await expectAnchorError(claimDefault(), 'NotInDefault');
+    await sleep(4);
+
+    const refund = downPayment.muln(refundBps).divn(10000).toNumber();
+    const planRent = (await balance(planPda(car))) - downPayment.toNumber();
+    const buyerBefore = await balance(buyer.keypair.publicKey);
+    const sellerBefore = await balance(seller.keypair.publicKey);
+
+    await claimDefault();
+
+    expect(await balance(buyer.keypair.publicKey)).toBe(buyerBefore + refund);
+    expect(await balance(seller.keypair.publicKey)).toBe(
+      sellerBefore + downPayment.toNumber() - refund + planRent
+    );
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.owner.toString()).toBe(seller.keypair.publicKey.toString());
+    expect(carAccount.installmentPlan).toBeNull();
+  }, 30000);
+
+  it('✅ Should let the seller withdraw a plan the buyer has not accepted', async () => {
+    const { vin, car } = await registerCar(seller.keypair);
+    await proposePlan(vin, car, 3600, 0);
+
+    await program.methods
+      .cancelInstallmentPlan(vin)
+      .accounts({
+        installmentPlan: planPda(car),
+        car,
+        buyer: buyer.keypair.publicKey,
+        seller: seller.keypair.publicKey,
+        authority: seller.keypair.publicKey,
+      })
+      .signers([seller.keypair])
+      .rpc();
+
+    expect(await program.account.installmentPlan.fetchNullable(planPda(car))).toBeNull();
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.installmentPlan).toBeNull();
+  }, 15000);
+
+  it('❌ Should not let the seller force a default by reporting the car stolen', async () => {
+    const { vin, car } = await registerCar(seller.keypair);
+    await proposePlan(vin, car, 3, 2);
+    await acceptPlan(vin, car);
+
+    const claimDefault = () =>
+      program.methods
+        .claimInstallmentDefault(vin)
+        .accounts({
+          installmentPlan: planPda(car),
+          car,
+          seller: seller.keypair.publicKey,
+          buyer: buyer.keypair.publicKey,
+        })
+        .signers([seller.keypair])
+        .rpc();
+
+    // The seller still holds the title, so they may flag the car
+    await program.methods
+      .reportStolen(vin)
+      .accounts({
+        car: carPda(vin),
+        reporter: seller.keypair.publicKey,
+        reporterUser: null,
+        coOwnership: null,
+        organization: null,
+      })
+      .signers([seller.keypair])
+      .rpc();
+    await expectAnchorError(payInstallment(vin, car), 'CarReportedStolen');
+
+    // The instalment falls due while the buyer is locked out
+    await sleep(7);
+    await expectAnchorError(claimDefault(), 'CarReportedStolen');
+
+    const { nextDueAt } = await program.account.installmentPlan.fetch(planPda(car));
+    await program.methods
+      .recoverVehicle(vin)
+      .accounts({
+        car: carPda(vin),
+        authority: seller.keypair.publicKey,
+        authorityUser: null,
+        coOwnership: null,
+        organization: null,
+        installmentPlan: planPda(car),
+      })
+      .signers([seller.keypair])
+      .rpc();
+
+    // The schedule moved back by the stolen period, so the buyer is not in default
+    const plan = await program.account.installmentPlan.fetch(planPda(car));
+    expect(plan.nextDueAt.gt(nextDueAt.addn(6))).toBe(true);
+    await expectAnchorError(claimDefault(), 'NotInDefault');
+
+    await payInstallment(vin, car);
+    const paid = await program.account.installmentPlan.fetch(planPda(car));
+    expect(paid.installmentsPaid).toBe(1);
+  }, 45000);
+});
@@ -0,0 +1,131 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  FEES,
+  TestUser,
+  acceptBuyRequest,
+  balance,
+  carPda,
+  ensureConfigs,
+  expectAnchorError,
+  findPda,
+  initiateTransfer,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  setForSale,
+  taxReceiptPda,
+} from './helpers';
+
+describe('🏦 Car Chain - Liens', () => {
+  const price = new BN(2 * LAMPORTS_PER_SOL);
+  const principal = new BN(LAMPORTS_PER_SOL);
+  const reducedPayoff = new BN(0.8 * LAMPORTS_PER_SOL);
+
+  let lender: TestUser;
+  let owner: TestUser;
+  let buyer: TestUser;
+  let vin: string;
+  let car: PublicKey;
+
+  const lienPda = (car: PublicKey) => findPda(Buffer.from('lien'), car.toBuffer());
+
+  const registerLien = (vin: string) =>
+    program.methods
+      .registerLien(vin, 'LOAN-42', principal, principal)
+      .accounts({
+        lien: lienPda(carPda(vin)),
+        car: carPda(vin),
+        lenderUser: lender.pda,
+        lender: lender.keypair.publicKey,
+        owner: owner.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([lender.keypair, owner.keypair])
+      .rpc();
+
+  const updatePayoff = (outstanding: BN, signer = lender) =>
+    program.methods
+      .updateLienPayoff(outstanding)
+      .accounts({ lien: lienPda(car), lender: signer.keypair.publicKey })
+      .signers([signer.keypair])
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    lender = await registerVerifiedUser({ lender: {} });
+    owner = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    ({ vin, car } = await registerCar(owner.keypair));
+  }, 60000);
+
+  it('✅ Should register a lien with the consent of the owner', async () => {
+    await registerLien(vin);
+
+    const lien = await program.account.lien.fetch(lienPda(car));
+    expect(lien.lender.toString()).toBe(lender.keypair.publicKey.toString());
+    expect(lien.outstanding.toString()).toBe(principal.toString());
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.lienholder?.toString()).toBe(lender.keypair.publicKey.toString());
+  }, 15000);
+
+  it('❌ Should block gifts and transfers while the lien is active', async () => {
+    await expectAnchorError(
+      initiateTransfer(owner.keypair, vin, buyer.keypair.publicKey),
+      'CarHasLien'
+    );
+  }, 15000);
+
+  it('❌ Should only let the lender lower the payoff', async () => {
+    await expectAnchorError(updatePayoff(reducedPayoff, owner), 'ConstraintHasOne');
+    await expectAnchorError(updatePayoff(principal), 'PayoffIncrease');
+
+    await updatePayoff(reducedPayoff);
+    const lien = await program.account.lien.fetch(lienPda(car));
+    expect(lien.outstanding.toString()).toBe(reducedPayoff.toString());
+  }, 15000);
+
+  it('✅ Should pay off the lender out of the sale proceeds', async () => {
+    await setForSale(owner.keypair, vin, price);
+    await requestBuy(buyer, vin);
+
+    const saleFee = price.muln(FEES.saleFeeBps).divn(10000);
+    const lienRent = await balance(lienPda(car));
+    const lenderBefore = await balance(lender.keypair.publicKey);
+    const ownerBefore = await balance(owner.keypair.publicKey);
+
+    const transferIndex = await acceptBuyRequest(owner, buyer, vin, {
+      accounts: { lien: lienPda(car), lienholder: lender.keypair.publicKey },
+    });
+
+    const recordRent =
+      (await balance(taxReceiptPda(car, transferIndex))) +
+      (await balance(ownershipRecordPda(car, transferIndex)));
+    expect(await balance(lender.keypair.publicKey)).toBe(
+      lenderBefore + reducedPayoff.toNumber() + lienRent
+    );
+    expect(await balance(owner.keypair.publicKey)).toBe(
+      ownerBefore + price.sub(saleFee).sub(reducedPayoff).toNumber() - recordRent
+    );
+    expect(await program.account.lien.fetchNullable(lienPda(car))).toBeNull();
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.lienholder).toBeNull();
+  }, 30000);
+
+  it('✅ Should let the lender release a repaid lien', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+    await registerLien(vin);
+
+    await program.methods
+      .releaseLien(vin)
+      .accounts({ lien: lienPda(car), car, lender: lender.keypair.publicKey })
+      .signers([lender.keypair])
+      .rpc();
+
+    expect(await program.account.lien.fetchNullable(lienPda(car))).toBeNull();
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.lienholder).toBeNull();
+  }, 30000);
+});
@@ -0,0 +1,151 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, SystemProgram } from '@solana/web3.js';
+import {
+  TestUser,
+  balance,
+  buyRequestPda,
+  carPda,
+  ensureConfigs,
+  expectAnchorError,
+  feeConfigPda,
+  ownershipRecordPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  setForSale,
+  taxConfigPda,
+  taxReceiptPda,
+  taxTreasuryPda,
+  treasuryPda,
+} from './helpers';
+
+describe('🏷️ Car Chain - Listing Price Changes', () => {
+  const listPrice = new BN(2 * LAMPORTS_PER_SOL);
+  const newPrice = new BN(1.5 * LAMPORTS_PER_SOL);
+
+  let seller: TestUser;
+  let buyer: TestUser;
+  let recipient: TestUser;
+  let vin: string;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    seller = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    recipient = await registerVerifiedUser();
+    ({ vin } = await registerCar(seller.keypair));
+    await setForSale(seller.keypair, vin, listPrice);
+  }, 60000);
+
+  const reclaim = () =>
+    program.methods
+      .reclaimBuyRequest(vin)
+      .accounts({
+        buyRequest: buyRequestPda(vin, buyer.keypair.publicKey),
+        car: carPda(vin),
+        buyer: buyer.keypair.publicKey,
+        organization: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer.keypair])
+      .rpc();
+
+  it('❌ Should keep the escrow locked while the listing is unchanged', async () => {
+    const buyRequest = await requestBuy(buyer, vin);
+
+    const request = await program.account.buyRequest.fetch(buyRequest);
+    expect(request.amount.toString()).toBe(listPrice.toString());
+    expect(request.listingVersion).toBe(1);
+
+    await expectAnchorError(reclaim(), 'BuyRequestStillActive');
+    console.log('✅ Reclaim refused on an unchanged listing');
+  }, 15000);
+
+  it('✅ Should update the price and bump the listing version', async () => {
+    await program.methods
+      .updateListingPrice(vin, newPrice)
+      .accounts({
+        carAccount: carPda(vin),
+        authority: seller.keypair.publicKey,
+        coOwnership: null,
+        organization: null,
+      })
+      .signers([seller.keypair])
+      .rpc();
+
+    const car = await program.account.carAccount.fetch(carPda(vin));
+    expect(car.salePrice?.toString()).toBe(newPrice.toString());
+    expect(car.listingVersion).toBe(2);
+    console.log('✅ Listing price updated');
+  }, 15000);
+
+  it('❌ Should reject a price update from someone other than the owner', async () => {
+    await expectAnchorError(
+      program.methods
+        .updateListingPrice(vin, listPrice)
+        .accounts({
+          carAccount: carPda(vin),
+          authority: buyer.keypair.publicKey,
+          coOwnership: null,
+          organization: null,
+        })
+        .signers([buyer.keypair])
+        .rpc(),
+      'NotCarOwner'
+    );
+  }, 15000);
+
+  it('✅ Should refund the whole escrow once the price has changed', async () => {
+    const buyRequest = buyRequestPda(vin, buyer.keypair.publicKey);
+    const escrowed = await balance(buyRequest);
+    const buyerBefore = await balance(buyer.keypair.publicKey);
+
+    await reclaim();
+
+    expect(await program.account.buyRequest.fetchNullable(buyRequest)).toBeNull();
+    expect(await balance(buyer.keypair.publicKey)).toBe(buyerBefore + escrowed);
+    console.log(`✅ ${escrowed} lamports returned to the buyer`);
+  }, 15000);
+
+  it('✅ Should delist on a direct transfer and refund requests made to the old owner', async () => {
+    const buyRequest = await requestBuy(buyer, vin);
+    await expectAnchorError(reclaim(), 'BuyRequestStillActive');
+    const car = carPda(vin);
+
+    await program.methods
+      .transferCar(vin, recipient.userName)
+      .accounts({
+        car,
+        authority: seller.keypair.publicKey,
+        newOwner: recipient.keypair.publicKey,
+        newOwnerPda: recipient.pda,
+        feeConfig: feeConfigPda,
+        treasury: treasuryPda,
+        taxConfig: taxConfigPda,
+        taxTreasury: taxTreasuryPda,
+        taxReceipt: taxReceiptPda(car, 0),
+        ownershipRecord: ownershipRecordPda(car, 0),
+        coOwnership: null,
+        organization: null,
+        newOwnerOrganization: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([seller.keypair, recipient.keypair])
+      .rpc();
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.owner.toString()).toBe(recipient.keypair.publicKey.toString());
+    expect(carAccount.isForSale).toBe(false);
+    expect(carAccount.salePrice).toBeNull();
+    expect(carAccount.listingVersion).toBe(3);
+
+    const escrowed = await balance(buyRequest);
+    const buyerBefore = await balance(buyer.keypair.publicKey);
+
+    await reclaim();
+
+    expect(await program.account.buyRequest.fetchNullable(buyRequest)).toBeNull();
+    expect(await balance(buyer.keypair.publicKey)).toBe(buyerBefore + escrowed);
+  }, 30000);
+});
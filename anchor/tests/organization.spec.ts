@@ -0,0 +1,202 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import { randomBytes } from 'crypto';
+import {
+  FEES,
+  TestUser,
+  acceptBuyRequest,
+  balance,
+  buyRequestPda,
+  carPda,
+  ensureConfigs,
+  expectAnchorError,
+  findPda,
+  fundedKeypair,
+  governmentKeypair,
+  initiateTransfer,
+  inspectionConfigPda,
+  pendingTransferPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  setForSale,
+  taxConfigPda,
+} from './helpers';
+
+describe('🏢 Car Chain - Organization Fleets', () => {
+  const price = new BN(2 * LAMPORTS_PER_SOL);
+  const name = `Fleet ${randomBytes(4).toString('hex')}`;
+  const organization = findPda(Buffer.from('organization'), Buffer.from(name));
+
+  let admin: TestUser;
+  let fleetManager: Keypair;
+  let seller: TestUser;
+  let buyer: TestUser;
+  let vin: string;
+  let car: PublicKey;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    admin = await registerVerifiedUser();
+    seller = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    fleetManager = await fundedKeypair();
+    ({ vin, car } = await registerCar(seller.keypair));
+    await setForSale(seller.keypair, vin, price);
+  }, 60000);
+
+  // The organization as a party to a sale: it has no user account, so the
+  // sale helpers pass no user PDA for it
+  const organizationParty = () =>
+    ({ keypair: { publicKey: organization }, pda: null, userName: name }) as unknown as TestUser;
+
+  const requestBuyForOrganization = () =>
+    program.methods
+      .requestBuy(vin, null, 0)
+      .accounts({
+        buyRequest: buyRequestPda(vin, organization),
+        car,
+        buyerPda: admin.pda,
+        taxConfig: taxConfigPda,
+        buyer: admin.keypair.publicKey,
+        organization,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin.keypair])
+      .rpc();
+
+  it('✅ Should register an organization with its creator as admin', async () => {
+    await program.methods
+      .registerOrganization(name, 'CR-123456')
+      .accounts({
+        organization,
+        admin: admin.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin.keypair])
+      .rpc();
+
+    const account = await program.account.organization.fetch(organization);
+    expect(account.name).toBe(name);
+    expect(account.verificationStatus).toHaveProperty('pending');
+    expect(account.members).toHaveLength(1);
+    expect(account.members[0].key.toString()).toBe(admin.keypair.publicKey.toString());
+    expect(account.members[0].role).toHaveProperty('admin');
+  }, 15000);
+
+  it('❌ Should not let an unverified organization buy', async () => {
+    await expectAnchorError(requestBuyForOrganization(), 'OrganizationNotVerified');
+  }, 15000);
+
+  it('✅ Should let the admin add a fleet manager once verified', async () => {
+    await program.methods
+      .verifyOrganization(true)
+      .accounts({ organization, government: governmentKeypair.publicKey })
+      .signers([governmentKeypair])
+      .rpc();
+
+    await program.methods
+      .updateOrganizationMember(fleetManager.publicKey, { fleetManager: {} })
+      .accounts({ organization, admin: admin.keypair.publicKey })
+      .signers([admin.keypair])
+      .rpc();
+
+    const account = await program.account.organization.fetch(organization);
+    expect(account.verificationStatus).toHaveProperty('verified');
+    expect(account.members[1].key.toString()).toBe(fleetManager.publicKey.toString());
+    expect(account.members[1].role).toHaveProperty('fleetManager');
+  }, 15000);
+
+  it('✅ Should let the admin buy a car for the organization', async () => {
+    await requestBuyForOrganization();
+
+    const { transferCount } = await program.account.carAccount.fetch(car);
+    await acceptBuyRequest(seller, organizationParty(), vin, {
+      accounts: { buyerOrganization: organization },
+    });
+
+    const account = await program.account.carAccount.fetch(car);
+    expect(account.owner.toString()).toBe(organization.toString());
+    expect(account.transferCount).toBe(transferCount + 1);
+  }, 30000);
+
+  it('✅ Should pay the proceeds of a fleet sale to the organization', async () => {
+    await program.methods
+      .setForSale(vin, price)
+      .accounts({
+        carAccount: car,
+        authority: fleetManager.publicKey,
+        coOwnership: null,
+        organization,
+        inspectionConfig: inspectionConfigPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([fleetManager])
+      .rpc();
+    await requestBuy(buyer, vin);
+
+    const saleFee = price.muln(FEES.saleFeeBps).divn(10000).toNumber();
+    const organizationBefore = await balance(organization);
+
+    await acceptBuyRequest(organizationParty(), buyer, vin, {
+      authority: fleetManager,
+      accounts: { organization },
+    });
+
+    expect(await balance(organization)).toBe(organizationBefore + price.toNumber() - saleFee);
+    const account = await program.account.carAccount.fetch(carPda(vin));
+    expect(account.owner.toString()).toBe(buyer.keypair.publicKey.toString());
+  }, 30000);
+
+  it('❌ Should only let an admin withdraw organization funds', async () => {
+    await expectAnchorError(
+      program.methods
+        .withdrawOrganizationFunds(new BN(LAMPORTS_PER_SOL))
+        .accounts({ organization, admin: fleetManager.publicKey, recipient: fleetManager.publicKey })
+        .signers([fleetManager])
+        .rpc(),
+      'NotOrganizationAdmin'
+    );
+  }, 15000);
+
+  it('✅ Should let an admin withdraw organization funds', async () => {
+    const recipient = Keypair.generate();
+
+    await program.methods
+      .withdrawOrganizationFunds(new BN(LAMPORTS_PER_SOL))
+      .accounts({ organization, admin: admin.keypair.publicKey, recipient: recipient.publicKey })
+      .signers([admin.keypair])
+      .rpc();
+
+    expect(await balance(recipient.publicKey)).toBe(LAMPORTS_PER_SOL);
+  }, 15000);
+
+  it('✅ Should let an admin decline a transfer offered to the organization', async () => {
+    const { vin: offeredVin, car: offeredCar } = await registerCar(seller.keypair);
+    const pendingTransfer = await initiateTransfer(seller.keypair, offeredVin, organization);
+
+    const decline = (recipient: Keypair, withOrganization = true) =>
+      program.methods
+        .declineTransfer()
+        .accounts({
+          pendingTransfer,
+          initiator: seller.keypair.publicKey,
+          recipient: recipient.publicKey,
+          organization: withOrganization ? organization : null,
+        })
+        .signers([recipient])
+        .rpc();
+
+    await expectAnchorError(decline(admin.keypair, false), 'UnauthorizedRecipient');
+    await expectAnchorError(decline(fleetManager), 'NotOrganizationAdmin');
+
+    const escrowed = await balance(pendingTransfer);
+    const sellerBefore = await balance(seller.keypair.publicKey);
+
+    await decline(admin.keypair);
+
+    expect(await program.account.pendingTransfer.fetchNullable(pendingTransferPda(offeredCar))).toBeNull();
+    expect(await balance(seller.keypair.publicKey)).toBe(sellerBefore + escrowed);
+  }, 30000);
+});
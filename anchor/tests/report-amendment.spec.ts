@@ -0,0 +1,118 @@
+import { BN } from '@coral-xyz/anchor';
+import { PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  TestUser,
+  acceptCarReport,
+  carReportPda,
+  documentDigest,
+  ensureConfigs,
+  expectAnchorError,
+  inspectCar,
+  inspectorStatsPda,
+  passingChecklist,
+  program,
+  registerCar,
+  registerInspectionCenter,
+  registerVerifiedUser,
+} from './helpers';
+
+describe('📝 Car Chain - Report Amendment and Revocation', () => {
+  const amendedUri = 'ipfs://QmAmendedReport';
+  // Brakes, the first checklist item, failed
+  const failingChecklist = { ...passingChecklist, results: passingChecklist.results | 0b11 };
+
+  let owner: TestUser;
+  let inspector: TestUser;
+  let center: PublicKey;
+  let vin: string;
+  let car: PublicKey;
+  let original: { report: PublicKey; reportId: BN };
+  let amended: { report: PublicKey; reportId: BN };
+
+  const revoke = (report: PublicKey, authority: TestUser) =>
+    program.methods
+      .revokeReport()
+      .accounts({ carReport: report, conformityReport: null, car, authority: authority.keypair.publicKey })
+      .signers([authority.keypair])
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    inspector = await registerVerifiedUser({ inspector: {} });
+    ({ center } = await registerInspectionCenter([inspector]));
+    ({ vin, car } = await registerCar(owner.keypair));
+    original = await inspectCar(owner.keypair, inspector, center, vin);
+    await acceptCarReport(owner.keypair, vin, inspector, original.reportId);
+  }, 60000);
+
+  it('✅ Should issue a linked revision and supersede the original', async () => {
+    const reportId = original.reportId.addn(1);
+    const report = carReportPda(car, inspector.keypair.publicKey, reportId);
+
+    await program.methods
+      .amendReport(reportId, {
+        passed: false,
+        overallCondition: 5,
+        engineCondition: 4,
+        bodyCondition: 7,
+        checklist: failingChecklist,
+        fullReportUri: amendedUri,
+        fullReportDigest: documentDigest(amendedUri, 'application/pdf'),
+        reportSummary: 'Brakes below the legal minimum',
+        notes: 'Corrects the brake reading',
+      })
+      .accounts({
+        previousReport: original.report,
+        carReport: report,
+        car,
+        inspector: inspector.pda,
+        inspectorSigner: inspector.keypair.publicKey,
+        inspectorStats: inspectorStatsPda(inspector.pda),
+        inspectionCenter: center,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([inspector.keypair])
+      .rpc();
+    amended = { report, reportId };
+
+    const previous = await program.account.carReport.fetch(original.report);
+    const revision = await program.account.carReport.fetch(report);
+    expect(previous.status).toHaveProperty('superseded');
+    expect(previous.supersededBy?.toString()).toBe(report.toString());
+    expect(revision.status).toHaveProperty('pending');
+    expect(revision.revision).toBe(1);
+    expect(revision.previousRevision?.toString()).toBe(original.report.toString());
+    expect(revision.reportDate.toString()).toBe(previous.reportDate.toString());
+    expect(revision.passed).toBe(false);
+
+    // The accepted original no longer vouches for the car while the revision is pending
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.inspectionStatus).toHaveProperty('pending');
+    expect(carAccount.inspectionExpiresAt).toBeNull();
+  }, 30000);
+
+  it('✅ Should apply the amended revision once the owner accepts it', async () => {
+    await acceptCarReport(owner.keypair, vin, inspector, amended.reportId);
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.inspectionStatus).toHaveProperty('failed');
+    expect(carAccount.latestInspectionReport).toBe(amended.report.toString());
+  }, 15000);
+
+  it('❌ Should only let the issuer or the government revoke', async () => {
+    await expectAnchorError(revoke(amended.report, owner), 'NotReportIssuer');
+  }, 15000);
+
+  it('✅ Should reset the car when its current report is revoked', async () => {
+    await revoke(amended.report, inspector);
+
+    const revoked = await program.account.carReport.fetch(amended.report);
+    expect(revoked.status).toHaveProperty('revoked');
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.inspectionStatus).toHaveProperty('pending');
+    expect(carAccount.inspectionExpiresAt).toBeNull();
+
+    await expectAnchorError(revoke(amended.report, inspector), 'ReportNotLive');
+  }, 15000);
+});
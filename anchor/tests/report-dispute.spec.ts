@@ -0,0 +1,165 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  TestUser,
+  carPda,
+  documentDigest,
+  ensureConfigs,
+  expectAnchorError,
+  findPda,
+  governmentKeypair,
+  inspectCar,
+  inspectionConfigPda,
+  inspectorStatsPda,
+  program,
+  registerCar,
+  registerInspectionCenter,
+  registerVerifiedUser,
+  u64le,
+} from './helpers';
+
+describe('⚖️ Car Chain - Report Disputes', () => {
+  const evidenceUri = 'ipfs://QmIndependentInspection';
+
+  let owner: TestUser;
+  let stranger: TestUser;
+  let inspector: TestUser;
+  let expert: TestUser;
+  let center: PublicKey;
+
+  const disputePda = (report: PublicKey) => findPda(Buffer.from('report_dispute'), report.toBuffer());
+
+  const dispute = (
+    vin: string,
+    reports: { carReport: PublicKey | null; conformityReport: PublicKey | null },
+    disputer = owner
+  ) => {
+    const report = (reports.carReport ?? reports.conformityReport)!;
+    return program.methods
+      .disputeReport(
+        report,
+        'Brakes were replaced last week',
+        evidenceUri,
+        documentDigest(evidenceUri, 'application/pdf')
+      )
+      .accounts({
+        ...reports,
+        reportDispute: disputePda(report),
+        car: carPda(vin),
+        owner: disputer.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([disputer.keypair])
+      .rpc();
+  };
+
+  const resolve = (
+    vin: string,
+    report: PublicKey,
+    resolution: { upheld: {} } | { voided: {} },
+    accounts: { carReport?: PublicKey; conformityReport?: PublicKey; inspectorStats?: PublicKey },
+    government: Keypair = governmentKeypair
+  ) =>
+    program.methods
+      .resolveDispute(resolution)
+      .accounts({
+        reportDispute: disputePda(report),
+        carReport: null,
+        conformityReport: null,
+        inspectorStats: null,
+        car: carPda(vin),
+        inspectionConfig: inspectionConfigPda,
+        government: government.publicKey,
+        ...accounts,
+      })
+      .signers([government])
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    stranger = await registerVerifiedUser();
+    inspector = await registerVerifiedUser({ inspector: {} });
+    expert = await registerVerifiedUser({ confirmityExpert: {} });
+    ({ center } = await registerInspectionCenter([inspector]));
+  }, 60000);
+
+  it('✅ Should apply an inspection report the government upholds', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+    const { report } = await inspectCar(owner.keypair, inspector, center, vin);
+
+    await expectAnchorError(
+      dispute(vin, { carReport: report, conformityReport: null }, stranger),
+      'NotCarOwner'
+    );
+    await dispute(vin, { carReport: report, conformityReport: null });
+
+    const disputed = await program.account.carReport.fetch(report);
+    expect(disputed.status).toHaveProperty('disputed');
+    const disputeAccount = await program.account.reportDispute.fetch(disputePda(report));
+    expect(disputeAccount.report.toString()).toBe(report.toString());
+    expect(disputeAccount.disputedBy.toString()).toBe(owner.keypair.publicKey.toString());
+    expect(disputeAccount.evidenceUri).toBe(evidenceUri);
+    expect(disputeAccount.resolution).toBeNull();
+
+    const accounts = { carReport: report, inspectorStats: inspectorStatsPda(inspector.pda) };
+    await expectAnchorError(
+      resolve(vin, report, { upheld: {} }, accounts, owner.keypair),
+      'UnauthorizedVerifier'
+    );
+    await resolve(vin, report, { upheld: {} }, accounts);
+
+    const upheld = await program.account.carReport.fetch(report);
+    expect(upheld.status).toHaveProperty('upheld');
+    const resolved = await program.account.reportDispute.fetch(disputePda(report));
+    expect(resolved.resolution).toHaveProperty('upheld');
+    expect(resolved.resolvedBy?.toString()).toBe(governmentKeypair.publicKey.toString());
+    // An upheld report counts as accepted
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.inspectionStatus).toHaveProperty('passed');
+    expect(carAccount.latestInspectionReport).toBe(report.toString());
+  }, 30000);
+
+  it('✅ Should void a disputed conformity report', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+    const reportId = new BN(Date.now());
+    const report = findPda(
+      Buffer.from('conformity_report'),
+      car.toBuffer(),
+      expert.keypair.publicKey.toBuffer(),
+      u64le(reportId)
+    );
+    const fullReportUri = 'ipfs://QmConformityReport';
+
+    await program.methods
+      .issueConfirmityReport(
+        reportId,
+        vin,
+        false,
+        'Non-standard exhaust',
+        fullReportUri,
+        documentDigest(fullReportUri, 'application/pdf'),
+        'mines_stamp_data',
+        ''
+      )
+      .accounts({
+        conformityReport: report,
+        car,
+        confirmityExpert: expert.pda,
+        confirmityExpertSigner: expert.keypair.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([expert.keypair])
+      .rpc();
+
+    await dispute(vin, { carReport: null, conformityReport: report });
+    await resolve(vin, report, { voided: {} }, { conformityReport: report });
+
+    const voided = await program.account.conformityReport.fetch(report);
+    expect(voided.status).toHaveProperty('voided');
+    await expectAnchorError(
+      resolve(vin, report, { upheld: {} }, { conformityReport: report }),
+      'ReportNotDisputed'
+    );
+  }, 30000);
+});
@@ -0,0 +1,146 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import {
+  TAX,
+  TestUser,
+  acceptBuyRequest,
+  airdrop,
+  balance,
+  ensureConfigs,
+  expectAnchorError,
+  governmentKeypair,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  rentOf,
+  requestBuy,
+  setForSale,
+  taxReceiptPda,
+  taxTreasuryPda,
+} from './helpers';
+
+describe('🏛️ Car Chain - Transfer Tax', () => {
+  // Specs register 2010 models, which fall in the last age bracket
+  const ageRateBps = TAX.ageBrackets[TAX.ageBrackets.length - 1].rateBps;
+  const surchargeBps = TAX.priceBrackets[0].surchargeBps;
+  const taxFor = (price: BN, rateBps: number) => price.muln(rateBps).divn(10000);
+
+  let seller: TestUser;
+  let buyer: TestUser;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    seller = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    await airdrop(buyer.keypair.publicKey, 15);
+  }, 60000);
+
+  describe('💰 Sale below the price surcharge', () => {
+    const price = new BN(2 * LAMPORTS_PER_SOL);
+    const tax = taxFor(price, ageRateBps);
+
+    let vin: string;
+    let car: PublicKey;
+    let buyRequest: PublicKey;
+
+    beforeAll(async () => {
+      ({ vin, car } = await registerCar(seller.keypair));
+      await setForSale(seller.keypair, vin, price);
+    }, 30000);
+
+    it('✅ Should escrow the price plus tax with the buy request', async () => {
+      const buyerBefore = await balance(buyer.keypair.publicKey);
+
+      buyRequest = await requestBuy(buyer, vin);
+
+      const request = await program.account.buyRequest.fetch(buyRequest);
+      expect(request.taxRateBps).toBe(ageRateBps);
+      expect(request.taxAmount.toString()).toBe(tax.toString());
+
+      const rent = await rentOf(buyRequest);
+      expect(await balance(buyRequest)).toBe(rent + price.add(tax).toNumber());
+      expect(await balance(buyer.keypair.publicKey)).toBe(
+        buyerBefore - rent - price.add(tax).toNumber()
+      );
+      console.log(`✅ ${tax.toString()} lamports of tax escrowed`);
+    }, 15000);
+
+    it('✅ Should pay the tax into the tax treasury and issue a receipt', async () => {
+      const taxTreasuryBefore = await program.account.taxTreasury.fetch(taxTreasuryPda);
+      const taxTreasuryLamports = await balance(taxTreasuryPda);
+
+      const transferIndex = await acceptBuyRequest(seller, buyer, vin);
+
+      expect(await balance(taxTreasuryPda)).toBe(taxTreasuryLamports + tax.toNumber());
+      const taxTreasuryAfter = await program.account.taxTreasury.fetch(taxTreasuryPda);
+      expect(taxTreasuryAfter.totalCollected.sub(taxTreasuryBefore.totalCollected).toString()).toBe(
+        tax.toString()
+      );
+
+      const receipt = await program.account.taxReceipt.fetch(taxReceiptPda(car, transferIndex));
+      expect(receipt.transferIndex).toBe(transferIndex);
+      expect(receipt.car.toString()).toBe(car.toString());
+      expect(receipt.payer.toString()).toBe(buyer.keypair.publicKey.toString());
+      expect(receipt.previousOwner.toString()).toBe(seller.keypair.publicKey.toString());
+      expect(receipt.salePrice?.toString()).toBe(price.toString());
+      expect(receipt.rateBps).toBe(ageRateBps);
+      expect(receipt.amount.toString()).toBe(tax.toString());
+      console.log('✅ Tax receipt issued');
+    }, 15000);
+  });
+
+  describe('💎 Sale above the price surcharge', () => {
+    const price = TAX.priceBrackets[0].minPrice.add(new BN(LAMPORTS_PER_SOL));
+
+    it('✅ Should add the price surcharge to the age rate', async () => {
+      const { vin } = await registerCar(seller.keypair);
+      await setForSale(seller.keypair, vin, price);
+
+      const request = await program.account.buyRequest.fetch(await requestBuy(buyer, vin));
+
+      expect(request.taxRateBps).toBe(ageRateBps + surchargeBps);
+      expect(request.taxAmount.toString()).toBe(
+        taxFor(price, ageRateBps + surchargeBps).toString()
+      );
+    }, 30000);
+  });
+
+  describe('🏦 Tax treasury withdrawals', () => {
+    const amount = new BN(0.01 * LAMPORTS_PER_SOL);
+
+    it('❌ Should reject a withdrawal by anyone but the government', async () => {
+      const impostor = Keypair.generate();
+
+      await expectAnchorError(
+        program.methods
+          .withdrawTaxTreasury(amount)
+          .accounts({
+            taxTreasury: taxTreasuryPda,
+            government: impostor.publicKey,
+            recipient: impostor.publicKey,
+          })
+          .signers([impostor])
+          .rpc(),
+        'UnauthorizedVerifier'
+      );
+    }, 15000);
+
+    it('✅ Should let the government withdraw collected tax', async () => {
+      const recipient = Keypair.generate();
+      const taxTreasuryLamports = await balance(taxTreasuryPda);
+
+      await program.methods
+        .withdrawTaxTreasury(amount)
+        .accounts({
+          taxTreasury: taxTreasuryPda,
+          government: governmentKeypair.publicKey,
+          recipient: recipient.publicKey,
+        })
+        .signers([governmentKeypair])
+        .rpc();
+
+      expect(await balance(recipient.publicKey)).toBe(amount.toNumber());
+      expect(await balance(taxTreasuryPda)).toBe(taxTreasuryLamports - amount.toNumber());
+    }, 15000);
+  });
+});
@@ -0,0 +1,120 @@
+import { BN } from '@coral-xyz/anchor';
+import { LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import {
+  TestUser,
+  balance,
+  buyRequestPda,
+  carPda,
+  ensureConfigs,
+  expectAnchorError,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  requestBuy,
+  setForSale,
+} from './helpers';
+
+describe('🚨 Car Chain - Theft Reports', () => {
+  const price = new BN(2 * LAMPORTS_PER_SOL);
+
+  let owner: TestUser;
+  let buyer: TestUser;
+  let police: TestUser;
+  let stranger: TestUser;
+  let vin: string;
+  let car: PublicKey;
+
+  const reportStolen = (vin: string, reporter: TestUser, asPolice = false) =>
+    program.methods
+      .reportStolen(vin)
+      .accounts({
+        car: carPda(vin),
+        reporter: reporter.keypair.publicKey,
+        reporterUser: asPolice ? reporter.pda : null,
+        coOwnership: null,
+        organization: null,
+      })
+      .signers([reporter.keypair])
+      .rpc();
+
+  const recoverVehicle = (vin: string, authority: TestUser, asPolice = false) =>
+    program.methods
+      .recoverVehicle(vin)
+      .accounts({
+        car: carPda(vin),
+        authority: authority.keypair.publicKey,
+        authorityUser: asPolice ? authority.pda : null,
+        coOwnership: null,
+        organization: null,
+        installmentPlan: null,
+      })
+      .signers([authority.keypair])
+      .rpc();
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    buyer = await registerVerifiedUser();
+    stranger = await registerVerifiedUser();
+    police = await registerVerifiedUser({ lawEnforcement: {} });
+    ({ vin, car } = await registerCar(owner.keypair));
+    await setForSale(owner.keypair, vin, price);
+  }, 60000);
+
+  it('❌ Should not let a stranger report a car stolen', async () => {
+    await expectAnchorError(reportStolen(vin, stranger), 'UnauthorizedTheftReport');
+  }, 15000);
+
+  it('✅ Should take a stolen car off the market', async () => {
+    await requestBuy(buyer, vin);
+    await reportStolen(vin, owner);
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.isStolen).toBe(true);
+    expect(carAccount.stolenReportedBy?.toString()).toBe(owner.keypair.publicKey.toString());
+    expect(carAccount.isForSale).toBe(false);
+    expect(carAccount.salePrice).toBeNull();
+
+    await expectAnchorError(setForSale(owner.keypair, vin, price), 'CarReportedStolen');
+  }, 15000);
+
+  it('✅ Should let the buyer reclaim the escrow of a stolen car', async () => {
+    const buyRequest = buyRequestPda(vin, buyer.keypair.publicKey);
+    const escrowed = await balance(buyRequest);
+    const buyerBefore = await balance(buyer.keypair.publicKey);
+
+    await program.methods
+      .reclaimBuyRequest(vin)
+      .accounts({
+        buyRequest,
+        car,
+        buyer: buyer.keypair.publicKey,
+        organization: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([buyer.keypair])
+      .rpc();
+
+    expect(await balance(buyer.keypair.publicKey)).toBe(buyerBefore + escrowed);
+  }, 15000);
+
+  it('✅ Should let the owner withdraw their own report', async () => {
+    await recoverVehicle(vin, owner);
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.isStolen).toBe(false);
+    expect(carAccount.stolenReportedBy).toBeNull();
+    await setForSale(owner.keypair, vin, price);
+  }, 15000);
+
+  it('✅ Should leave a police report for the police to close', async () => {
+    const { vin, car } = await registerCar(owner.keypair);
+
+    await reportStolen(vin, police, true);
+    await expectAnchorError(recoverVehicle(vin, owner), 'UnauthorizedTheftReport');
+    await recoverVehicle(vin, police, true);
+
+    const carAccount = await program.account.carAccount.fetch(car);
+    expect(carAccount.isStolen).toBe(false);
+  }, 30000);
+});
@@ -0,0 +1,189 @@
+import { BN } from '@coral-xyz/anchor';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import {
+  FEES,
+  TAX,
+  TestUser,
+  acceptTransfer,
+  balance,
+  carPda,
+  ensureConfigs,
+  expectAnchorError,
+  fundedKeypair,
+  initiateTransfer,
+  ownershipRecordPda,
+  pendingTransferPda,
+  program,
+  registerCar,
+  registerVerifiedUser,
+  rentOf,
+  taxReceiptPda,
+  taxTreasuryPda,
+  treasuryPda,
+} from './helpers';
+
+describe('📨 Car Chain - Two-Step Transfers', () => {
+  let owner: TestUser;
+  let recipient: TestUser;
+  let stranger: TestUser;
+
+  beforeAll(async () => {
+    await ensureConfigs();
+    owner = await registerVerifiedUser();
+    recipient = await registerVerifiedUser();
+    stranger = await registerVerifiedUser();
+  }, 60000);
+
+  describe('✅ Accepted transfer', () => {
+    let vin: string;
+    let car: PublicKey;
+    let pendingTransfer: PublicKey;
+
+    beforeAll(async () => {
+      ({ vin, car } = await registerCar(owner.keypair));
+    }, 30000);
+
+    it('✅ Should escrow the transfer fee when the owner initiates', async () => {
+      pendingTransfer = await initiateTransfer(owner.keypair, vin, recipient.keypair.publicKey);
+
+      const account = await program.account.pendingTransfer.fetch(pendingTransfer);
+      expect(account.recipient.toString()).toBe(recipient.keypair.publicKey.toString());
+      expect(account.previousOwner.toString()).toBe(owner.keypair.publicKey.toString());
+      expect(account.feeAmount.toString()).toBe(FEES.transferFee.toString());
+      expect(await balance(pendingTransfer)).toBe(
+        (await rentOf(pendingTransfer)) + FEES.transferFee.toNumber()
+      );
+
+      // The car does not change hands until the recipient accepts
+      const carAccount = await program.account.carAccount.fetch(car);
+      expect(carAccount.owner.toString()).toBe(owner.keypair.publicKey.toString());
+    }, 15000);
+
+    it('❌ Should not let anyone but the recipient accept', async () => {
+      await expectAnchorError(
+        acceptTransfer(owner.keypair.publicKey, stranger, vin),
+        'UnauthorizedRecipient'
+      );
+    }, 15000);
+
+    it('✅ Should move the car, pay the fee and charge the recipient the flat tax', async () => {
+      const pendingRent = await rentOf(pendingTransfer);
+      const treasuryBefore = await balance(treasuryPda);
+      const taxTreasuryBefore = await balance(taxTreasuryPda);
+      const ownerBefore = await balance(owner.keypair.publicKey);
+      const recipientBefore = await balance(recipient.keypair.publicKey);
+
+      const transferIndex = await acceptTransfer(owner.keypair.publicKey, recipient, vin);
+
+      const receipt = taxReceiptPda(car, transferIndex);
+      const record = ownershipRecordPda(car, transferIndex);
+      const recordRent = (await balance(receipt)) + (await balance(record));
+
+      expect(await balance(treasuryPda)).toBe(treasuryBefore + FEES.transferFee.toNumber());
+      expect(await balance(taxTreasuryPda)).toBe(taxTreasuryBefore + TAX.flatTransferTax.toNumber());
+      expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore + pendingRent);
+      expect(await balance(recipient.keypair.publicKey)).toBe(
+        recipientBefore - TAX.flatTransferTax.toNumber() - recordRent
+      );
+      expect(await program.account.pendingTransfer.fetchNullable(pendingTransfer)).toBeNull();
+
+      const carAccount = await program.account.carAccount.fetch(car);
+      expect(carAccount.owner.toString()).toBe(recipient.keypair.publicKey.toString());
+      const receiptAccount = await program.account.taxReceipt.fetch(receipt);
+      expect(receiptAccount.salePrice).toBeNull();
+      expect(receiptAccount.amount.toString()).toBe(TAX.flatTransferTax.toString());
+      const recordAccount = await program.account.ownershipRecord.fetch(record);
+      expect(recordAccount.mechanism).toHaveProperty('gift');
+    }, 15000);
+  });
+
+  describe('↩️ Declined and cancelled transfers', () => {
+    let vin: string;
+    let car: PublicKey;
+
+    const cancel = (authority: Keypair, initiator = owner.keypair.publicKey) =>
+      program.methods
+        .cancelTransfer()
+        .accounts({
+          pendingTransfer: pendingTransferPda(car),
+          car,
+          initiator,
+          authority: authority.publicKey,
+          coOwnership: null,
+          organization: null,
+        })
+        .signers([authority])
+        .rpc();
+
+    beforeAll(async () => {
+      ({ vin, car } = await registerCar(owner.keypair));
+    }, 30000);
+
+    it('✅ Should refund the fee and rent when the recipient declines', async () => {
+      const pendingTransfer = await initiateTransfer(owner.keypair, vin, recipient.keypair.publicKey);
+      const escrowed = await balance(pendingTransfer);
+      const ownerBefore = await balance(owner.keypair.publicKey);
+
+      await program.methods
+        .declineTransfer()
+        .accounts({
+          pendingTransfer,
+          initiator: owner.keypair.publicKey,
+          recipient: recipient.keypair.publicKey,
+          organization: null,
+        })
+        .signers([recipient.keypair])
+        .rpc();
+
+      expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore + escrowed);
+      const carAccount = await program.account.carAccount.fetch(car);
+      expect(carAccount.owner.toString()).toBe(owner.keypair.publicKey.toString());
+    }, 15000);
+
+    it('❌ Should not let a stranger cancel an unexpired transfer', async () => {
+      await initiateTransfer(owner.keypair, vin, recipient.keypair.publicKey);
+
+      await expectAnchorError(cancel(stranger.keypair), 'UnauthorizedInitiator');
+    }, 15000);
+
+    it('✅ Should refund the fee and rent when the initiator cancels', async () => {
+      const pendingTransfer = pendingTransferPda(carPda(vin));
+      const escrowed = await balance(pendingTransfer);
+      const ownerBefore = await balance(owner.keypair.publicKey);
+
+      await cancel(owner.keypair);
+
+      expect(await balance(owner.keypair.publicKey)).toBe(ownerBefore + escrowed);
+      expect(await program.account.pendingTransfer.fetchNullable(pendingTransfer)).toBeNull();
+    }, 15000);
+
+    it('✅ Should let the owner cancel a transfer started by a revoked delegate', async () => {
+      const delegate = await fundedKeypair();
+      await program.methods
+        .approveDelegate(
+          vin,
+          delegate.publicKey,
+          { list: false, acceptOffers: false, transfer: true },
+          new BN(Math.floor(Date.now() / 1000) + 60 * 60)
+        )
+        .accounts({ carAccount: car, owner: owner.keypair.publicKey })
+        .signers([owner.keypair])
+        .rpc();
+      const pendingTransfer = await initiateTransfer(delegate, vin, stranger.keypair.publicKey);
+      await program.methods
+        .revokeDelegate(vin)
+        .accounts({ carAccount: car, owner: owner.keypair.publicKey })
+        .signers([owner.keypair])
+        .rpc();
+
+      const escrowed = await balance(pendingTransfer);
+      const delegateBefore = await balance(delegate.publicKey);
+
+      await cancel(owner.keypair, delegate.publicKey);
+
+      // The escrow goes back to whoever paid it
+      expect(await balance(delegate.publicKey)).toBe(delegateBefore + escrowed);
+      expect(await program.account.pendingTransfer.fetchNullable(pendingTransfer)).toBeNull();
+    }, 30000);
+  });
+});
@@ -0,0 +1,78 @@
+use anchor_lang::{AccountDeserialize, Discriminator};
+use car_chain::state::{
+    AccidentRecord, CarReport, ConformityReport, DeregistrationRecord, DocumentDigest,
+    ForcedTransferRecord, InspectionCenter, ReportDispute, UserAccount,
+};
+
+// An off-chain document referenced by an account, with the digest stored next to it
+pub struct DocumentField {
+    pub name: &'static str,
+    pub uri: String,
+    pub digest: DocumentDigest,
+}
+
+fn field(name: &'static str, uri: &str, digest: &DocumentDigest) -> DocumentField {
+    DocumentField {
+        name,
+        uri: uri.to_string(),
+        digest: digest.clone(),
+    }
+}
+
+fn deserialize<T: AccountDeserialize>(data: &[u8]) -> Result<T, String> {
+    T::try_deserialize(&mut &data[..]).map_err(|e| format!("cannot decode account: {e}"))
+}
+
+// Every document field of a car-chain account, recognised by its discriminator
+pub fn document_fields(data: &[u8]) -> Result<(&'static str, Vec<DocumentField>), String> {
+    if data.starts_with(CarReport::DISCRIMINATOR) {
+        let report: CarReport = deserialize(data)?;
+        return Ok(("CarReport", vec![
+            field("full_report_uri", &report.full_report_uri, &report.full_report_digest),
+        ]));
+    }
+    if data.starts_with(ConformityReport::DISCRIMINATOR) {
+        let report: ConformityReport = deserialize(data)?;
+        return Ok(("ConformityReport", vec![
+            field("full_report_uri", &report.full_report_uri, &report.full_report_digest),
+        ]));
+    }
+    if data.starts_with(UserAccount::DISCRIMINATOR) {
+        let user: UserAccount = deserialize(data)?;
+        return Ok(("UserAccount", vec![
+            field("public_data_uri", &user.public_data_uri, &user.public_data_digest),
+            field("private_data_uri", &user.private_data_uri, &user.private_data_digest),
+        ]));
+    }
+    if data.starts_with(DeregistrationRecord::DISCRIMINATOR) {
+        let record: DeregistrationRecord = deserialize(data)?;
+        return Ok(("DeregistrationRecord", vec![
+            field("certificate_uri", &record.certificate_uri, &record.certificate_digest),
+        ]));
+    }
+    if data.starts_with(AccidentRecord::DISCRIMINATOR) {
+        let record: AccidentRecord = deserialize(data)?;
+        return Ok(("AccidentRecord", vec![
+            field("document_uri", &record.document_uri, &record.document_digest),
+        ]));
+    }
+    if data.starts_with(ReportDispute::DISCRIMINATOR) {
+        let dispute: ReportDispute = deserialize(data)?;
+        return Ok(("ReportDispute", vec![
+            field("evidence_uri", &dispute.evidence_uri, &dispute.evidence_digest),
+        ]));
+    }
+    if data.starts_with(ForcedTransferRecord::DISCRIMINATOR) {
+        let record: ForcedTransferRecord = deserialize(data)?;
+        return Ok(("ForcedTransferRecord", vec![
+            field("document_uri", &record.document_uri, &record.document_digest),
+        ]));
+    }
+    if data.starts_with(InspectionCenter::DISCRIMINATOR) {
+        let center: InspectionCenter = deserialize(data)?;
+        return Ok(("InspectionCenter", vec![
+            field("address_uri", &center.address_uri, &center.address_digest),
+        ]));
+    }
+    Err("account holds no off-chain documents".to_string())
+}
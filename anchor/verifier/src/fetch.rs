@@ -0,0 +1,38 @@
+use std::io::Read;
+
+// Public gateway used for ipfs:// URIs unless CAR_CHAIN_IPFS_GATEWAY is set
+const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+pub struct Document {
+    pub bytes: Vec<u8>,
+    pub media_type: Option<String>, // Content-Type when fetched over HTTP
+}
+
+// Reads a document from a local path, file://, http(s):// or ipfs:// URI
+pub fn fetch(source: &str) -> Result<Document, String> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        return fetch_http(source);
+    }
+    if let Some(cid) = source.strip_prefix("ipfs://") {
+        let gateway = std::env::var("CAR_CHAIN_IPFS_GATEWAY")
+            .unwrap_or_else(|_| DEFAULT_IPFS_GATEWAY.to_string());
+        return fetch_http(&format!("{}/{}", gateway.trim_end_matches('/'), cid));
+    }
+    let path = source.strip_prefix("file://").unwrap_or(source);
+    let bytes = std::fs::read(path).map_err(|e| format!("cannot read {path}: {e}"))?;
+    Ok(Document { bytes, media_type: None })
+}
+
+fn fetch_http(url: &str) -> Result<Document, String> {
+    let response = ureq::get(url)
+        .call()
+        .map_err(|e| format!("cannot fetch {url}: {e}"))?;
+    let media_type = response.header("Content-Type").map(str::to_string);
+
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("cannot read {url}: {e}"))?;
+    Ok(Document { bytes, media_type })
+}
@@ -0,0 +1,222 @@
+//! Checks the off-chain documents referenced by a car-chain account against
+//! the SHA-256 digest and media type stored next to each URI.
+//!
+//!     verify-document <ACCOUNT> [FIELD] [--file PATH] [--url RPC_URL]
+//!
+//! Without FIELD every document of the account is checked. `--file` checks a
+//! local copy instead of fetching the URI and needs FIELD when the account
+//! has more than one document. Exits with 1 when any document does not match.
+
+mod documents;
+mod fetch;
+
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::hash;
+use car_chain::state::DocumentDigest;
+use solana_client::rpc_client::RpcClient;
+
+use documents::{document_fields, DocumentField};
+use fetch::{fetch, Document};
+
+const DEFAULT_RPC_URL: &str = "http://127.0.0.1:8899";
+
+struct Args {
+    account: Pubkey,
+    field: Option<String>,
+    file: Option<String>,
+    rpc_url: String,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args().skip(1);
+    let mut positional = Vec::new();
+    let mut file = None;
+    let mut rpc_url = DEFAULT_RPC_URL.to_string();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--file" => file = Some(args.next().ok_or("--file needs a path")?),
+            "--url" => rpc_url = args.next().ok_or("--url needs an RPC URL")?,
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let account = positional
+        .next()
+        .ok_or("usage: verify-document <ACCOUNT> [FIELD] [--file PATH] [--url RPC_URL]")?;
+    let account = Pubkey::from_str(&account).map_err(|e| format!("invalid account {account}: {e}"))?;
+
+    Ok(Args {
+        account,
+        field: positional.next(),
+        file,
+        rpc_url,
+    })
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+// Media types are compared without parameters such as "; charset=utf-8"
+fn same_media_type(expected: &str, served: &str) -> bool {
+    let served = served.split(';').next().unwrap_or_default().trim();
+    expected.eq_ignore_ascii_case(served)
+}
+
+fn check(document: &Document, digest: &DocumentDigest) -> Result<(), String> {
+    let actual = hash(&document.bytes).to_bytes();
+    if actual != digest.sha256 {
+        return Err(format!(
+            "hash mismatch: on-chain {}, document {}",
+            hex(&digest.sha256),
+            hex(&actual)
+        ));
+    }
+    if let Some(served) = &document.media_type {
+        if !same_media_type(&digest.media_type, served) {
+            return Err(format!(
+                "media type mismatch: on-chain {}, served as {served}",
+                digest.media_type
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn verify_field(field: &DocumentField, file: Option<&str>) -> bool {
+    if field.uri.is_empty() {
+        println!("➖ {}: no document", field.name);
+        return true;
+    }
+    if field.digest == DocumentDigest::default() {
+        println!("❌ {}: no digest recorded for {}", field.name, field.uri);
+        return false;
+    }
+
+    let source = file.unwrap_or(&field.uri);
+    match fetch(source).and_then(|document| check(&document, &field.digest)) {
+        Ok(()) => {
+            println!("✅ {}: {} matches ({})", field.name, source, field.digest.media_type);
+            true
+        }
+        Err(e) => {
+            println!("❌ {}: {}: {e}", field.name, source);
+            false
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let args = parse_args()?;
+
+    let client = RpcClient::new(args.rpc_url.clone());
+    let account = client
+        .get_account(&args.account)
+        .map_err(|e| format!("cannot load {}: {e}", args.account))?;
+    // Anyone can create an account whose data mimics a car-chain discriminator
+    if account.owner != car_chain::ID {
+        return Err(format!(
+            "{} is owned by {}, not the car-chain program",
+            args.account, account.owner
+        ));
+    }
+    let (kind, fields) = document_fields(&account.data)?;
+
+    let fields: Vec<DocumentField> = match &args.field {
+        Some(name) => {
+            let selected: Vec<_> = fields.into_iter().filter(|f| f.name == name.as_str()).collect();
+            if selected.is_empty() {
+                return Err(format!("{kind} has no document field {name}"));
+            }
+            selected
+        }
+        None => fields,
+    };
+    if args.file.is_some() && fields.len() > 1 {
+        return Err(format!("{kind} has several documents, name the FIELD to check with --file"));
+    }
+
+    println!("🔍 {kind} {}", args.account);
+    let mut all_match = true;
+    for field in &fields {
+        all_match &= verify_field(field, args.file.as_deref());
+    }
+    Ok(all_match)
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("❌ {e}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest_of(bytes: &[u8], media_type: &str) -> DocumentDigest {
+        DocumentDigest {
+            sha256: hash(bytes).to_bytes(),
+            media_type: media_type.to_string(),
+        }
+    }
+
+    fn document(bytes: &[u8], media_type: Option<&str>) -> Document {
+        Document {
+            bytes: bytes.to_vec(),
+            media_type: media_type.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn hex_encodes_lowercase_pairs() {
+        assert_eq!(hex(&[0x00, 0x0f, 0xab, 0xff]), "000fabff");
+    }
+
+    #[test]
+    fn digest_is_sha256() {
+        let digest = digest_of(b"abc", "text/plain");
+        assert_eq!(
+            hex(&digest.sha256),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn media_type_ignores_parameters_and_case() {
+        assert!(same_media_type("text/plain", "text/plain; charset=utf-8"));
+        assert!(same_media_type("application/pdf", "Application/PDF"));
+        assert!(!same_media_type("application/pdf", "text/plain"));
+    }
+
+    #[test]
+    fn matching_document_passes() {
+        let digest = digest_of(b"report", "application/pdf");
+        assert!(check(&document(b"report", Some("application/pdf")), &digest).is_ok());
+        assert!(check(&document(b"report", None), &digest).is_ok());
+    }
+
+    #[test]
+    fn altered_document_fails() {
+        let digest = digest_of(b"report", "application/pdf");
+        let error = check(&document(b"rep0rt", Some("application/pdf")), &digest).unwrap_err();
+        assert!(error.starts_with("hash mismatch"));
+    }
+
+    #[test]
+    fn wrong_media_type_fails() {
+        let digest = digest_of(b"report", "application/pdf");
+        let error = check(&document(b"report", Some("text/html")), &digest).unwrap_err();
+        assert!(error.starts_with("media type mismatch"));
+    }
+}